@@ -0,0 +1,52 @@
+//! Measures conversions of unicode-heavy strings, which exercise the casing
+//! of characters whose lowercase or uppercase form is several characters.
+//!
+//! Run with `cargo bench`. This uses no benchmarking framework, so it only
+//! prints the mean time of each conversion.
+
+use std::time::Instant;
+
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToTitleCase, ToUpperCamelCase};
+
+const ITERATIONS: u32 = 10_000;
+
+fn bench(name: &str, input: &str, convert: fn(&str) -> String) {
+    let mut len = 0;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        len += convert(input).len();
+    }
+    let elapsed = start.elapsed();
+    let micros = elapsed.as_secs() as f64 * 1e6 + f64::from(elapsed.subsec_nanos()) / 1e3;
+    // Print the total length so that the conversions can't be optimized out.
+    println!(
+        "{:<24} {:>8.2} µs/iter ({} bytes)",
+        name,
+        micros / f64::from(ITERATIONS),
+        len
+    );
+}
+
+fn main() {
+    let greek = "ΣΑΣ ΤΟΥ ΛΌΓΟΥ ΚΑΙ ΤΗΣ ΠΡΆΞΗΣ ".repeat(20);
+    let german = "Straße GROẞ Fußgängerübergänge ".repeat(20);
+    let ligatures = "baﬄe ﬁnancial ﬂour ǆungla ǉubav ".repeat(20);
+
+    for &(name, input) in &[
+        ("greek", &greek),
+        ("german", &german),
+        ("ligatures", &ligatures),
+    ] {
+        bench(&format!("{} snake", name), input, |s| s.to_snake_case());
+        bench(&format!("{} shouty snake", name), input, |s| {
+            s.to_shouty_snake_case()
+        });
+        bench(&format!("{} upper camel", name), input, |s| {
+            s.to_upper_camel_case()
+        });
+        bench(&format!("{} lower camel", name), input, |s| {
+            s.to_lower_camel_case()
+        });
+        bench(&format!("{} title", name), input, |s| s.to_title_case());
+    }
+}
@@ -0,0 +1,99 @@
+#![no_main]
+
+use std::borrow::Cow;
+use std::str;
+
+use heck::*;
+use libfuzzer_sys::fuzz_target;
+
+const CASES: &[Case] = &[
+    Case::UpperCamel,
+    Case::LowerCamel,
+    Case::Snake,
+    Case::Kebab,
+    Case::ShoutySnake,
+    Case::ShoutyKebab,
+    Case::Title,
+];
+
+/// Builds a converter with the options selected by the bits of `flags`.
+fn converter(flags: u8) -> Converter {
+    let converter = Converter::new()
+        .split_acronyms(flags & 1 != 0)
+        .ordinals(flags & 2 != 0)
+        .collapse_runs(flags & 4 != 0)
+        .merge_single_letters(flags & 8 != 0)
+        .keep_spacing(flags & 16 != 0)
+        .keep_dunder(flags & 32 != 0)
+        .known_acronyms(vec!["HTTP", "URL", "ΣΑΣ"])
+        .preserve_words(vec!["iOS", "ǅ"])
+        .keep_together(vec!["GraphQL", "ΣΣ"])
+        .protect("{", "}");
+
+    let converter = match flags >> 6 {
+        0 => converter,
+        1 => converter
+            .digits(Digits::Separate)
+            .spell_digits(SpellDigits::Leading)
+            .acronyms(Acronyms::Trailing)
+            .acronym_style(AcronymStyle::Lower)
+            .smart_acronyms(true),
+        2 => converter
+            .boundary_char(Some('.'))
+            .keep_empty_segments(true)
+            .spell_digits(SpellDigits::All)
+            .acronyms(Acronyms::MinLength(2))
+            .lang(Lang::Dutch),
+        _ => converter.lang(Lang::Lithuanian),
+    };
+
+    converter
+}
+
+fuzz_target!(|data: &[u8]| {
+    let (flags, data) = match data.split_first() {
+        Some((&flags, data)) => (flags, data),
+        None => return,
+    };
+    let s = match str::from_utf8(data) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    // The outputs are `String`s, so they are valid UTF-8 as long as no
+    // conversion panics.
+    s.to_snake_case();
+    s.to_snake_case_stripping(&["get", "set"]);
+    s.to_snake_case_truncated(flags as usize);
+    s.to_snake_case_max_words(flags as usize % 8);
+    s.to_java_property_snake_case();
+    s.to_kebab_case();
+    s.to_shouty_snake_case();
+    s.to_shouty_kebab_case();
+    s.to_lower_camel_case();
+    s.to_upper_camel_case();
+    s.to_upper_camel_case_keep_ext();
+    s.to_upper_camel_case_with_last_word(|word| Cow::Owned(word.repeat(2)));
+    s.to_title_case();
+    s.to_title_case_keep_capitals();
+    s.to_toggle_case();
+    s.to_capitalized();
+    let _ = s.try_to_snake_case();
+
+    humanize(s);
+    fold_case(s);
+    to_acronym(s);
+    to_shouty_acronym(s);
+    to_separated(s, "_");
+    to_sql_ident(s, SqlDialect::Postgres);
+    would_be_valid_rust_ident(s);
+    conversion_stats(s);
+    snake_case_with_boundaries(s);
+    snake_case_chunks(s).for_each(drop);
+    assert_eq!(word_count(s), snake_case_with_boundaries(s).len());
+
+    let converter = converter(flags);
+    for &case in CASES {
+        assert_eq!(converter.convert_many(s, &[case]), [converter.convert(s, case)]);
+    }
+});
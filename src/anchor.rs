@@ -0,0 +1,95 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// This trait defines a conversion to the anchor which GitHub generates for a
+/// Markdown heading.
+///
+/// Unlike kebab case, the string is not split into words: it is trimmed and
+/// lowercased, punctuation and symbols are dropped while letters, digits,
+/// combining marks, hyphens and underscores are kept, and each space becomes
+/// a hyphen, so consecutive spaces give consecutive hyphens.
+///
+/// GitHub makes repeated headings unique by appending "-1", "-2" and so on to
+/// their anchors, which depends on the rest of the document and so is left to
+/// the caller.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::ToGithubAnchor;
+///
+/// assert_eq!("Hello, World!".to_github_anchor(), "hello-world");
+/// assert_eq!("C++ Guide".to_github_anchor(), "c-guide");
+/// ```
+pub trait ToGithubAnchor: ToOwned {
+    /// Convert this type to a GitHub heading anchor.
+    fn to_github_anchor(&self) -> Self::Owned;
+}
+
+impl ToGithubAnchor for str {
+    fn to_github_anchor(&self) -> String {
+        let mut out = String::new();
+
+        for grapheme in self.trim().to_lowercase().graphemes(true) {
+            for (i, c) in grapheme.char_indices() {
+                match c {
+                    ' ' => out.push('-'),
+                    '-' | '_' => out.push(c),
+                    _ if c.is_alphanumeric() => out.push(c),
+                    _ if i != 0 && is_mark(c) => out.push(c),
+                    _ => {}
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// Returns whether `c`, which extends the grapheme cluster of the character
+/// before it, is a combining mark.
+///
+/// Apart from combining marks, only a few kinds of characters extend grapheme
+/// clusters, so they are ruled out here instead of looking up the general
+/// category of `c`.
+fn is_mark(c: char) -> bool {
+    match c {
+        // Zero width non-joiner and joiner
+        '\u{200C}' | '\u{200D}'
+        // Regional indicators, which pair up into flags
+        | '\u{1F1E6}'..='\u{1F1FF}'
+        // Emoji skin tone modifiers
+        | '\u{1F3FB}'..='\u{1F3FF}'
+        // Tags, which follow emoji in tag sequences
+        | '\u{E0020}'..='\u{E007F}' => false,
+        _ => !c.is_alphanumeric() && !c.is_whitespace(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToGithubAnchor;
+
+    macro_rules! t {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_github_anchor(), $s2)
+            }
+        };
+    }
+
+    t!(test1: "Hello, World!" => "hello-world");
+    t!(test2: "C++ Guide" => "c-guide");
+    t!(test3: "Pre-release notes" => "pre-release-notes");
+    t!(test4: "snake_case and CamelCase" => "snake_case-and-camelcase");
+    t!(test5: "What's new in 0.4.0?" => "whats-new-in-040");
+    t!(test6: "a  -  b" => "a-----b");
+    t!(test7: "  Trimmed  " => "trimmed");
+    t!(test8: "Crème Brûlée 🎉" => "crème-brûlée-");
+    t!(test9: "ΛΌΓΟΣ" => "λόγος");
+    t!(test10: "" => "");
+    t!(test11: "Cafe\u{301} Menu" => "cafe\u{301}-menu");
+    t!(test12: "e\u{20DD} x" => "e\u{20DD}-x");
+    t!(test13: "👍🏽 ok 🇫🇷" => "-ok-");
+    t!(test14: "\u{301}a" => "a");
+}
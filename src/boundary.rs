@@ -0,0 +1,177 @@
+use crate::{lowercase_word, Words};
+
+/// The kind of word boundary which preceded a word in the input string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BoundaryKind {
+    /// The word is the first word of the input and nothing precedes it.
+    Start,
+    /// The word directly follows the previous word, and the boundary was
+    /// detected from a change of case (as in "camel|Case").
+    CaseChange,
+    /// The word was separated from the previous word by an underscore.
+    Underscore,
+    /// The word was separated from the previous word by a hyphen.
+    Hyphen,
+    /// The word was separated from the previous word by whitespace.
+    Space,
+    /// The word was separated from the previous word by a full stop.
+    Dot,
+    /// The word was separated from the previous word by some other character.
+    Other(char),
+}
+
+impl BoundaryKind {
+    /// Classifies the text found between two words (or before the first word)
+    /// by its first character.
+    pub(crate) fn from_separator(separator: &str, first_word: bool) -> BoundaryKind {
+        match separator.chars().next() {
+            None if first_word => BoundaryKind::Start,
+            None => BoundaryKind::CaseChange,
+            Some('_') => BoundaryKind::Underscore,
+            Some('-') => BoundaryKind::Hyphen,
+            Some('.') => BoundaryKind::Dot,
+            Some(c) if c.is_whitespace() => BoundaryKind::Space,
+            Some(c) => BoundaryKind::Other(c),
+        }
+    }
+}
+
+/// Converts a string to snake case words, remembering the boundary which
+/// preceded each word.
+///
+/// Joining the words with underscores gives the same result as `ToSnakeCase`,
+/// while the boundaries make it possible to reconstruct the separators of the
+/// original string.
+///
+/// When several separator characters occur between two words, the boundary is
+/// classified by the first of them.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{snake_case_with_boundaries, BoundaryKind};
+///
+/// assert_eq!(
+///     snake_case_with_boundaries("fooBar baz-qux"),
+///     vec![
+///         ("foo".to_owned(), BoundaryKind::Start),
+///         ("bar".to_owned(), BoundaryKind::CaseChange),
+///         ("baz".to_owned(), BoundaryKind::Space),
+///         ("qux".to_owned(), BoundaryKind::Hyphen),
+///     ]
+/// );
+/// ```
+pub fn snake_case_with_boundaries(s: &str) -> Vec<(String, BoundaryKind)> {
+    let mut words = Vec::new();
+    let mut prev_end = 0;
+
+    for (offset, word) in Words::new(s) {
+        let kind = BoundaryKind::from_separator(&s[prev_end..offset], words.is_empty());
+        let mut out = String::new();
+        lowercase_word(s, offset, word, &mut out);
+        words.push((out, kind));
+        prev_end = offset + word.len();
+    }
+
+    words
+}
+
+/// Converts a Rust path to a single snake case module name, separating the
+/// path segments with a double underscore and the words within them with a
+/// single underscore.
+///
+/// Any separator containing `::` counts as a path separator, so the origin of
+/// every boundary stays visible in the flattened name. Characters which are
+/// not allowed in identifiers but are kept within words, such as a lone ':'
+/// or an apostrophe, are replaced with an underscore.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::to_flat_module_name;
+///
+/// assert_eq!(to_flat_module_name("foo::BarBaz"), "foo__bar_baz");
+/// assert_eq!(to_flat_module_name("std::io::BufReader"), "std__io__buf_reader");
+/// ```
+pub fn to_flat_module_name(s: &str) -> String {
+    let mut out = String::new();
+    let mut prev_end = 0;
+
+    for (offset, word) in Words::new(s) {
+        if !out.is_empty() {
+            if s[prev_end..offset].contains("::") {
+                out.push_str("__");
+            } else {
+                out.push('_');
+            }
+        }
+        let start = out.len();
+        lowercase_word(s, offset, word, &mut out);
+        if out[start..].contains(|c: char| !is_ident_char(c)) {
+            let word: String = out[start..]
+                .chars()
+                .map(|c| if is_ident_char(c) { c } else { '_' })
+                .collect();
+            out.truncate(start);
+            out.push_str(&word);
+        }
+        prev_end = offset + word.len();
+    }
+
+    out
+}
+
+/// Returns whether `c` may appear in a module name.
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{snake_case_with_boundaries, to_flat_module_name, BoundaryKind};
+
+    macro_rules! t {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                let words: Vec<(&str, BoundaryKind)> = $s2.to_vec();
+                let expected: Vec<(String, BoundaryKind)> = words
+                    .into_iter()
+                    .map(|(word, kind)| (word.to_owned(), kind))
+                    .collect();
+                assert_eq!(snake_case_with_boundaries($s1), expected)
+            }
+        };
+    }
+
+    use BoundaryKind::*;
+
+    t!(test1: "CamelCase" => [("camel", Start), ("case", CaseChange)]);
+    t!(test2: "XMLHttpRequest" => [("xml", Start), ("http", CaseChange), ("request", CaseChange)]);
+    t!(test3: "snake_case" => [("snake", Start), ("case", Underscore)]);
+    t!(test4: "kebab-case" => [("kebab", Start), ("case", Hyphen)]);
+    t!(test5: "Human case" => [("human", Start), ("case", Space)]);
+    t!(test6: "one. two" => [("one", Start), ("two", Dot)]);
+    t!(test7: "foo🎉bar" => [("foo", Start), ("bar", Other('🎉'))]);
+    t!(test8: "__leading" => [("leading", Underscore)]);
+    t!(test9: "a__b" => [("a", Start), ("b", Underscore)]);
+    t!(test10: "" => []);
+
+    macro_rules! f {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(to_flat_module_name($s1), $s2)
+            }
+        };
+    }
+
+    f!(flat1: "foo::BarBaz" => "foo__bar_baz");
+    f!(flat2: "foo::bar::baz" => "foo__bar__baz");
+    f!(flat3: "::std::io" => "std__io");
+    f!(flat4: "crate::http_client::HTTPServer" => "crate__http_client__http_server");
+    f!(flat5: "foo_bar" => "foo_bar");
+    f!(flat6: "a:b" => "a_b");
+    f!(flat7: "" => "");
+    f!(flat8: "don't::a.b" => "don_t__a_b");
+}
@@ -0,0 +1,144 @@
+use crate::{capitalize, lowercase, transform};
+
+/// Converts `s` to camel case, capitalizing the first word only if
+/// `capitalize_first` is true.
+///
+/// This is UpperCamelCase when `capitalize_first` is true and lowerCamelCase
+/// otherwise, which is useful when the choice is only known at runtime. The
+/// `ToUpperCamelCase` and `ToLowerCamelCase` traits both delegate to it.
+///
+/// Converting a camel case string again gives the same string, except where
+/// a word of a single letter is capitalized next to another capital: "a_b"
+/// becomes "AB", "aBCd" becomes "ABCd" and "a1B" becomes "A1B". These can't
+/// round-trip, because a run of capitals has to be read as an acronym for
+/// "ID" to become "Id", so the second conversion gives "Ab", "AbCd" and
+/// "A1b". Making them stable would mean either not reading acronyms or
+/// merging single letter words on the first conversion, so they are left
+/// as they are.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::to_camel_case;
+///
+/// assert_eq!(to_camel_case("XMLHttpRequest", true), "XmlHttpRequest");
+/// assert_eq!(to_camel_case("XMLHttpRequest", false), "xmlHttpRequest");
+/// ```
+pub fn to_camel_case(s: &str, capitalize_first: bool) -> String {
+    transform(
+        s,
+        |word, out| {
+            if out.is_empty() && !capitalize_first {
+                lowercase(word, out);
+            } else {
+                capitalize(word, out);
+            }
+        },
+        |_| {},
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_camel_case;
+    use crate::{Case, ToLowerCamelCase, ToUpperCamelCase};
+
+    macro_rules! t {
+        ($t:ident : $s1:expr, $first:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(to_camel_case($s1, $first), $s2)
+            }
+        };
+    }
+
+    t!(test1: "CamelCase", true => "CamelCase");
+    t!(test2: "CamelCase", false => "camelCase");
+    t!(test3: "This is Human case.", true => "ThisIsHumanCase");
+    t!(test4: "This is Human case.", false => "thisIsHumanCase");
+    t!(test5: "XΣXΣ baﬄe", true => "XσxςBaﬄe");
+    t!(test6: "XΣXΣ baﬄe", false => "xσxςBaﬄe");
+
+    #[test]
+    fn matches_traits() {
+        for s in &["CamelCase", "snake_case", "XMLHttpRequest", "ǆemal", ""] {
+            assert_eq!(to_camel_case(s, true), s.to_upper_camel_case());
+            assert_eq!(to_camel_case(s, false), s.to_lower_camel_case());
+        }
+    }
+
+    /// Inputs which are already cased in various ways, all of which convert
+    /// to a string which converts to itself.
+    const STABLE: &[&str] = &[
+        "ABC",
+        "Abc",
+        "aBC",
+        "ABCd",
+        "AbCd",
+        "ABCDef",
+        "A1B",
+        "a1b",
+        "abc123Def",
+        "H2O",
+        "XYZ",
+        "Xy",
+        "ǆemal",
+        "ǅemal",
+        "ΣΑΣ",
+        "ﬁle",
+        "ßa",
+        "aß",
+        "Ǉa",
+        "foo2",
+        "2foo",
+        "2Foo",
+        "foo_2_bar",
+        "AB12cd",
+        "ab12CD",
+        "XMLHttpRequest",
+        "ﬀoo",
+        "Ǳ",
+    ];
+
+    #[test]
+    fn idempotent() {
+        for s in STABLE {
+            for &first in &[true, false] {
+                let once = to_camel_case(s, first);
+                assert_eq!(to_camel_case(&once, first), once, "{:?}", s);
+            }
+        }
+    }
+
+    macro_rules! twice {
+        ($t:ident : $s1:expr, $first:expr => $once:expr, $twice:expr) => {
+            #[test]
+            fn $t() {
+                let once = to_camel_case($s1, $first);
+                assert_eq!(once, $once);
+                assert_eq!(to_camel_case(&once, $first), $twice);
+            }
+        };
+    }
+
+    twice!(twice1: "ABCd", true => "AbCd", "AbCd");
+    twice!(twice2: "ABCd", false => "abCd", "abCd");
+    twice!(twice3: "aBC", true => "ABc", "ABc");
+    twice!(twice4: "aBC", false => "aBc", "aBc");
+    twice!(twice5: "ABCDef", true => "AbcDef", "AbcDef");
+
+    // Consecutive capitals are read as an acronym, so single letter words
+    // whose capitals end up next to another capital lose their boundary after
+    // one conversion. This can't be fixed without breaking acronyms, see the
+    // documentation of `to_camel_case`.
+    twice!(twice6: "a_b", true => "AB", "Ab");
+    twice!(twice7: "x_y_z", false => "xYZ", "xYz");
+    twice!(twice8: "aBCd", true => "ABCd", "AbCd");
+    twice!(twice9: "a1B", true => "A1B", "A1b");
+
+    #[test]
+    fn case_camel() {
+        assert_eq!(Case::camel(true), Case::UpperCamel);
+        assert_eq!(Case::camel(false), Case::LowerCamel);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::capitalize;
+
+/// This trait defines a conversion which capitalizes a whole string as a
+/// single word.
+///
+/// Unlike the case conversions, the string is not split into words: the first
+/// character is titlecased, the rest of the string is lowercased, and all
+/// other characters (including spaces and underscores) are kept.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::ToCapitalized;
+///
+/// let sentence = "WE ARE NOT IN THE LEAST AFRAID OF RUINS.";
+/// assert_eq!(sentence.to_capitalized(), "We are not in the least afraid of ruins.");
+/// ```
+pub trait ToCapitalized: ToOwned {
+    /// Convert this type to a capitalized string.
+    fn to_capitalized(&self) -> Self::Owned;
+}
+
+impl ToCapitalized for str {
+    fn to_capitalized(&self) -> String {
+        let mut out = String::with_capacity(self.len());
+        capitalize(self, &mut out);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToCapitalized;
+
+    macro_rules! t {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_capitalized(), $s2)
+            }
+        };
+    }
+
+    t!(test1: "fooBar" => "Foobar");
+    t!(test2: "FOO_BAR baz" => "Foo_bar baz");
+    t!(test3: "ΛΌΓΟΣ" => "Λόγος");
+    t!(test4: "ǆungla" => "ǅungla");
+    t!(test5: "ﬄe" => "Ffle");
+    t!(test6: "" => "");
+}
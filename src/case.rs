@@ -0,0 +1,100 @@
+use crate::lang::{capitalize_in, lowercase_in, uppercase_in, Lang};
+use crate::AcronymStyle;
+
+/// The cases which heck can convert to.
+///
+/// This is useful when the case is only known at runtime, and is used to
+/// select the target case of a `Converter`.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{Case, Converter};
+///
+/// let converter = Converter::new();
+/// assert_eq!(converter.convert("XMLHttpRequest", Case::Kebab), "xml-http-request");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// UpperCamelCase, see `ToUpperCamelCase`.
+    UpperCamel,
+    /// lowerCamelCase, see `ToLowerCamelCase`.
+    LowerCamel,
+    /// snake_case, see `ToSnakeCase`.
+    Snake,
+    /// kebab-case, see `ToKebabCase`.
+    Kebab,
+    /// SHOUTY_SNAKE_CASE, see `ToShoutySnakeCase`.
+    ShoutySnake,
+    /// SHOUTY-KEBAB-CASE, see `ToShoutyKebabCase`.
+    ShoutyKebab,
+    /// Title Case, see `ToTitleCase`.
+    Title,
+}
+
+impl Case {
+    /// Returns `UpperCamel` if `capitalize_first` is true, and `LowerCamel`
+    /// otherwise.
+    pub fn camel(capitalize_first: bool) -> Case {
+        if capitalize_first {
+            Case::UpperCamel
+        } else {
+            Case::LowerCamel
+        }
+    }
+
+    /// Pushes `word`, the word at `index` in the output, cased for this case
+    /// with the rules of `lang`.
+    pub(crate) fn push_word(self, index: usize, word: &str, lang: Option<Lang>, out: &mut String) {
+        match self {
+            Case::UpperCamel | Case::Title => capitalize_in(lang, word, out),
+            Case::LowerCamel if index == 0 => lowercase_in(lang, word, out),
+            Case::LowerCamel => capitalize_in(lang, word, out),
+            Case::Snake | Case::Kebab => lowercase_in(lang, word, out),
+            Case::ShoutySnake | Case::ShoutyKebab => uppercase_in(lang, word, out),
+        }
+    }
+
+    /// Like `push_word`, but cases `word` in `style` if this case would
+    /// capitalize it.
+    pub(crate) fn push_acronym(
+        self,
+        index: usize,
+        word: &str,
+        style: AcronymStyle,
+        lang: Option<Lang>,
+        out: &mut String,
+    ) {
+        let capitalized = match self {
+            Case::UpperCamel | Case::Title => true,
+            Case::LowerCamel => index != 0,
+            _ => false,
+        };
+
+        match style {
+            AcronymStyle::Upper if capitalized => uppercase_in(lang, word, out),
+            // The first word keeps the first capital of these cases.
+            AcronymStyle::Lower if capitalized && index != 0 => lowercase_in(lang, word, out),
+            _ => self.push_word(index, word, lang, out),
+        }
+    }
+
+    /// Pushes `word`, which continues the previous word, cased like the rest
+    /// of that word.
+    pub(crate) fn push_continuation(self, word: &str, lang: Option<Lang>, out: &mut String) {
+        match self {
+            Case::ShoutySnake | Case::ShoutyKebab => uppercase_in(lang, word, out),
+            _ => lowercase_in(lang, word, out),
+        }
+    }
+
+    /// The separator placed between words in this case.
+    pub(crate) fn separator(self) -> &'static str {
+        match self {
+            Case::UpperCamel | Case::LowerCamel => "",
+            Case::Snake | Case::ShoutySnake => "_",
+            Case::Kebab | Case::ShoutyKebab => "-",
+            Case::Title => " ",
+        }
+    }
+}
@@ -0,0 +1,178 @@
+/// Applies Unicode case folding to a string, for case-insensitive comparison.
+///
+/// Unlike lowercasing, folding maps every variant of a letter to the same
+/// form: "ß" folds to "ss", the final sigma "ς" folds to "σ" and ligatures
+/// such as "ﬁ" fold to their letters, so strings which differ only by such
+/// variants fold to the same string. The string is not split into words.
+///
+/// This is the full case folding of Unicode, so characters which fold to
+/// more than one character, such as "ᾳ" to "αι", are expanded.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::fold_case;
+///
+/// assert_eq!(fold_case("MASSE"), fold_case("Maße"));
+/// assert_eq!(fold_case("ﬁle"), "file");
+/// ```
+pub fn fold_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        fold_char(c, &mut out);
+    }
+    out
+}
+
+/// Pushes the case folding of `c`.
+///
+/// This is the full folding (statuses C and F) of CaseFolding.txt, given as
+/// the entries which differ from lowercasing.
+fn fold_char(c: char, out: &mut String) {
+    let folded = match c {
+        'µ' => "μ",
+        'ß' | 'ẞ' => "ss",
+        'ŉ' => "ʼn",
+        'ſ' => "s",
+        'ǰ' => "j\u{30C}",
+        '\u{345}' | '\u{1FBE}' => "ι",
+        '\u{390}' | '\u{1FD3}' => "ι\u{308}\u{301}",
+        '\u{3B0}' | '\u{1FE3}' => "υ\u{308}\u{301}",
+        'ς' => "σ",
+        'ϐ' => "β",
+        'ϑ' => "θ",
+        'ϕ' => "φ",
+        'ϖ' => "π",
+        'ϰ' => "κ",
+        'ϱ' => "ρ",
+        'ϵ' => "ε",
+        'և' => "եւ",
+        'ᲀ' => "в",
+        'ᲁ' => "д",
+        'ᲂ' => "о",
+        'ᲃ' => "с",
+        'ᲄ' | 'ᲅ' => "т",
+        'ᲆ' => "ъ",
+        'ᲇ' => "ѣ",
+        'ᲈ' => "ꙋ",
+        'ẖ' => "h\u{331}",
+        'ẗ' => "t\u{308}",
+        'ẘ' => "w\u{30A}",
+        'ẙ' => "y\u{30A}",
+        'ẚ' => "aʾ",
+        'ẛ' => "ṡ",
+        'ὐ' => "υ\u{313}",
+        'ὒ' => "υ\u{313}\u{300}",
+        'ὔ' => "υ\u{313}\u{301}",
+        'ὖ' => "υ\u{313}\u{342}",
+        'ᾲ' => "ὰι",
+        'ᾳ' | 'ᾼ' => "αι",
+        'ᾴ' => "άι",
+        'ᾶ' => "α\u{342}",
+        'ᾷ' => "α\u{342}ι",
+        'ῂ' => "ὴι",
+        'ῃ' | 'ῌ' => "ηι",
+        'ῄ' => "ήι",
+        'ῆ' => "η\u{342}",
+        'ῇ' => "η\u{342}ι",
+        'ῒ' => "ι\u{308}\u{300}",
+        'ῖ' => "ι\u{342}",
+        'ῗ' => "ι\u{308}\u{342}",
+        'ῢ' => "υ\u{308}\u{300}",
+        'ῤ' => "ρ\u{313}",
+        'ῦ' => "υ\u{342}",
+        'ῧ' => "υ\u{308}\u{342}",
+        'ῲ' => "ὼι",
+        'ῳ' | 'ῼ' => "ωι",
+        'ῴ' => "ώι",
+        'ῶ' => "ω\u{342}",
+        'ῷ' => "ω\u{342}ι",
+        'ﬀ' => "ff",
+        'ﬁ' => "fi",
+        'ﬂ' => "fl",
+        'ﬃ' => "ffi",
+        'ﬄ' => "ffl",
+        'ﬅ' | 'ﬆ' => "st",
+        'ﬓ' => "մն",
+        'ﬔ' => "մե",
+        'ﬕ' => "մի",
+        'ﬖ' => "վն",
+        'ﬗ' => "մխ",
+        // Cherokee folds to uppercase, which is the older part of the block.
+        '\u{13A0}'..='\u{13F5}' => {
+            out.push(c);
+            return;
+        }
+        '\u{13F8}'..='\u{13FD}' => {
+            out.push(offset(c, -8));
+            return;
+        }
+        '\u{AB70}'..='\u{ABBF}' => {
+            out.push(offset(c, 0x13A0 - 0xAB70));
+            return;
+        }
+        // Greek letters with an iota subscript or prosgegrammeni fold to the
+        // lowercase letter without it, followed by an iota.
+        '\u{1F80}'..='\u{1FAF}' => {
+            let row = (c as u32 - 0x1F80) / 16;
+            let base = [0x1F00, 0x1F20, 0x1F60][row as usize];
+            out.push(offset('\u{0}', base + (c as i32 & 7)));
+            out.push('ι');
+            return;
+        }
+        _ => {
+            out.extend(c.to_lowercase());
+            return;
+        }
+    };
+    out.push_str(folded);
+}
+
+/// The character `delta` code points away from `c`, which must be a valid
+/// character.
+fn offset(c: char, delta: i32) -> char {
+    std::char::from_u32((c as i32 + delta) as u32).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fold_case;
+
+    macro_rules! t {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(fold_case($s1), $s2)
+            }
+        };
+    }
+
+    t!(test1: "MASSE" => "masse");
+    t!(test2: "masse" => "masse");
+    t!(test3: "Maße" => "masse");
+    t!(test4: "ﬁle" => "file");
+    t!(test5: "ΣΑΣ" => "σασ");
+    t!(test6: "σας" => "σασ");
+    t!(test7: "ŉ" => "ʼn");
+    t!(test8: "İ" => "i\u{307}");
+    t!(test9: "ſtraße" => "strasse");
+    t!(test10: "FooBar_baz" => "foobar_baz");
+    t!(test11: "ǰ" => "j\u{30C}");
+    t!(test12: "\u{345}" => "ι");
+    t!(test13: "ᲀᲈ" => "вꙋ");
+    t!(test14: "ὐῶῒ" => "υ\u{313}ω\u{342}ι\u{308}\u{300}");
+    t!(test15: "ᾈᾳῼ" => "ἀιαιωι");
+    t!(test16: "ᏸꭰᎠ" => "ᏰᎠᎠ");
+
+    #[test]
+    fn variants_fold_equal() {
+        assert_eq!(fold_case("MASSE"), fold_case("masse"));
+        assert_eq!(fold_case("STRASSE"), fold_case("Straße"));
+        assert_eq!(fold_case("ΌΡΟΣ"), fold_case("όρος"));
+        assert_ne!(fold_case("file"), fold_case("fille"));
+        assert_eq!(fold_case("ᲀ"), fold_case("В"));
+        assert_eq!(fold_case("\u{345}"), fold_case("ι"));
+        assert_eq!(fold_case("ᾼ"), fold_case("ᾳ"));
+        assert_eq!(fold_case("ᎠᏴ"), fold_case("ꭰᏼ"));
+    }
+}
@@ -0,0 +1,162 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{is_ignorable, Words};
+
+/// The error returned by `validate_charset` when a converted string contains
+/// a character outside of the allowed set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidChar {
+    /// The offending character.
+    pub character: char,
+    /// The byte offset of the offending character in the converted string.
+    pub position: usize,
+}
+
+impl fmt::Display for InvalidChar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid character {:?} at byte {}",
+            self.character, self.position
+        )
+    }
+}
+
+impl Error for InvalidChar {}
+
+/// The error returned by fallible conversions, such as
+/// `ToSnakeCase::try_to_snake_case`, when characters of the input are
+/// dropped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LossyConversion {
+    /// The characters of the input which are not in the converted string, in
+    /// the order they appear in the input.
+    pub dropped: Vec<char>,
+    /// The converted string, without the dropped characters.
+    pub converted: String,
+}
+
+impl fmt::Display for LossyConversion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "conversion dropped characters {:?}", self.dropped)
+    }
+}
+
+impl Error for LossyConversion {}
+
+/// Returns `converted` if no characters of `s` were dropped by the conversion
+/// other than word separators, which are whitespace, underscores and hyphens,
+/// and invisible format characters.
+pub(crate) fn check_lossless(s: &str, converted: String) -> Result<String, LossyConversion> {
+    let dropped = dropped_chars(s);
+    if dropped.is_empty() {
+        Ok(converted)
+    } else {
+        Err(LossyConversion { dropped, converted })
+    }
+}
+
+/// The characters of `s` outside of its words, other than word separators and
+/// invisible format characters.
+pub(crate) fn dropped_chars(s: &str) -> Vec<char> {
+    let mut dropped = Vec::new();
+    for_each_dropped(s, |c| dropped.push(c));
+    dropped
+}
+
+/// Calls `f` with each character which `dropped_chars` would return, in
+/// order.
+pub(crate) fn for_each_dropped<F>(s: &str, mut f: F)
+where
+    F: FnMut(char),
+{
+    let mut prev_end = 0;
+
+    let mut push_gap = |gap: &str| {
+        gap.chars()
+            .filter(|&c| !(c.is_whitespace() || c == '_' || c == '-' || is_ignorable(c)))
+            .for_each(&mut f)
+    };
+
+    for (offset, word) in Words::new(s) {
+        push_gap(&s[prev_end..offset]);
+        prev_end = offset + word.len();
+    }
+    push_gap(&s[prev_end..]);
+}
+
+/// Checks that every character of a converted string is allowed by
+/// `is_allowed`, returning the string unchanged if so.
+///
+/// This is useful when the output of a conversion is sent to a system which
+/// only accepts a restricted set of characters, as heck preserves non-ASCII
+/// letters.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{validate_charset, ToSnakeCase};
+///
+/// let ascii = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_';
+///
+/// assert_eq!(validate_charset("FooBar".to_snake_case(), ascii).unwrap(), "foo_bar");
+///
+/// let err = validate_charset("Crème Brûlée".to_snake_case(), ascii).unwrap_err();
+/// assert_eq!((err.character, err.position), ('è', 2));
+/// ```
+pub fn validate_charset<F>(converted: String, is_allowed: F) -> Result<String, InvalidChar>
+where
+    F: Fn(char) -> bool,
+{
+    match converted.char_indices().find(|&(_, c)| !is_allowed(c)) {
+        Some((position, character)) => Err(InvalidChar {
+            character,
+            position,
+        }),
+        None => Ok(converted),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_charset, InvalidChar};
+    use crate::{ToKebabCase, ToSnakeCase};
+
+    fn ascii_snake(c: char) -> bool {
+        c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'
+    }
+
+    #[test]
+    fn ascii_input_is_valid() {
+        let converted = "XMLHttpRequest2".to_snake_case();
+        assert_eq!(
+            validate_charset(converted, ascii_snake),
+            Ok("xml_http_request2".to_owned())
+        );
+    }
+
+    #[test]
+    fn unicode_input_is_invalid() {
+        let converted = "naïve façade".to_snake_case();
+        assert_eq!(
+            validate_charset(converted, ascii_snake),
+            Err(InvalidChar {
+                character: 'ï',
+                position: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn separator_outside_charset_is_invalid() {
+        let converted = "foo bar".to_kebab_case();
+        assert_eq!(
+            validate_charset(converted, ascii_snake),
+            Err(InvalidChar {
+                character: '-',
+                position: 3,
+            })
+        );
+    }
+}
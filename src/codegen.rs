@@ -0,0 +1,417 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::iter;
+
+use crate::insensitive::snake_chars;
+use crate::{to_acronym, Case, Converter, ToShoutySnakeCase, ToSnakeCase};
+
+/// Generates Rust source declaring a constant for each of `names`, holding
+/// the name converted to `case`.
+///
+/// Each constant is named after the name in SHOUTY_SNAKE_CASE, and declared
+/// on a line of its own. Characters which are not allowed in identifiers are
+/// dropped from constant names, and a name which would start with a digit is
+/// prefixed with an underscore. This is meant to be called from build
+/// scripts, with the result written to a file which is then included with
+/// `include!`.
+///
+/// Two different names which give the same constant name would not compile,
+/// so they are reported as a `Collision` of the constant name. A name which
+/// is repeated is only declared once.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{generate_const_table, Case};
+///
+/// assert_eq!(
+///     generate_const_table(&["user id", "createdAt"], Case::Kebab).unwrap(),
+///     "pub const USER_ID: &str = \"user-id\";\n\
+///      pub const CREATED_AT: &str = \"created-at\";\n",
+/// );
+///
+/// let err = generate_const_table(&["fooBar", "foo_bar"], Case::Kebab).unwrap_err();
+/// assert_eq!(err.converted, "FOO_BAR");
+/// ```
+pub fn generate_const_table(names: &[&str], case: Case) -> Result<String, Collision> {
+    let converter = Converter::new();
+    let mut out = String::new();
+    let mut seen: HashMap<String, &str> = HashMap::new();
+
+    for &name in names {
+        let const_name = const_name(name);
+        if let Some(&first) = seen.get(&const_name) {
+            if first == name {
+                continue;
+            }
+            return Err(Collision {
+                first: first.to_owned(),
+                second: name.to_owned(),
+                converted: const_name,
+            });
+        }
+
+        writeln!(
+            out,
+            "pub const {}: &str = {:?};",
+            const_name,
+            converter.convert(name, case)
+        )
+        .unwrap();
+        seen.insert(const_name, name);
+    }
+
+    Ok(out)
+}
+
+/// The error returned by `snake_case_all_checked` and `generate_const_table`
+/// when two different inputs are converted to the same string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Collision {
+    /// The input which was converted first.
+    pub first: String,
+    /// The later input which was converted to the same string.
+    pub second: String,
+    /// The string which both inputs were converted to.
+    pub converted: String,
+}
+
+impl fmt::Display for Collision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} and {:?} are both converted to {:?}",
+            self.first, self.second, self.converted
+        )
+    }
+}
+
+impl Error for Collision {}
+
+/// Converts every item to snake case, failing if two different items are
+/// converted to the same string.
+///
+/// Generated code often needs a distinct identifier for each input, so this
+/// catches inputs which only differ in their separators or case. Items which
+/// are equal are not a collision. The error reports the first collision, in
+/// the order of the items.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::snake_case_all_checked;
+///
+/// assert_eq!(snake_case_all_checked(&["fooBar", "baz"]).unwrap(), vec!["foo_bar", "baz"]);
+///
+/// let err = snake_case_all_checked(&["fooBar", "foo_bar"]).unwrap_err();
+/// assert_eq!((err.first.as_str(), err.second.as_str()), ("fooBar", "foo_bar"));
+/// ```
+pub fn snake_case_all_checked<I>(items: I) -> Result<Vec<String>, Collision>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let items: Vec<I::Item> = items.into_iter().collect();
+    let inputs: Vec<&str> = items.iter().map(AsRef::as_ref).collect();
+    let converted: Vec<String> = inputs.iter().map(|input| input.to_snake_case()).collect();
+
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for (index, name) in converted.iter().enumerate() {
+        if let Some(&first) = seen.get(name.as_str()) {
+            let (first, second) = (inputs[first], inputs[index]);
+            if first != second {
+                return Err(Collision {
+                    first: first.to_owned(),
+                    second: second.to_owned(),
+                    converted: name.clone(),
+                });
+            }
+        } else {
+            seen.insert(name.as_str(), index);
+        }
+    }
+
+    Ok(converted)
+}
+
+/// Makes a short generic parameter name for a type name, which is not one of
+/// `used`.
+///
+/// The first candidate is the lowercased first letter of the name, then its
+/// initials, then the initials followed by 2, 3 and so on. Candidates which
+/// are keywords are skipped, and a name without initials gives "t". The
+/// result is not added to `used`, so callers generating several parameters
+/// insert each of them before making the next.
+///
+/// ## Example:
+///
+/// ```rust
+/// use std::collections::HashSet;
+///
+/// use heck::to_generic_param;
+///
+/// let mut used = HashSet::new();
+/// for (name, param) in &[("UserService", "u"), ("UserStore", "us"), ("UserSession", "us2")] {
+///     assert_eq!(to_generic_param(name, &used), *param);
+///     used.insert(param.to_string());
+/// }
+/// ```
+pub fn to_generic_param(s: &str, used: &HashSet<String>) -> String {
+    let initials: String = to_acronym(s)
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .skip_while(|c| c.is_numeric())
+        .collect();
+    let initials = if initials.is_empty() {
+        "t".to_owned()
+    } else {
+        initials
+    };
+    let first = initials.chars().next().map(|c| c.to_string()).unwrap();
+
+    iter::once(first)
+        .chain(iter::once(initials.clone()))
+        .chain((2..).map(|n| format!("{}{}", initials, n)))
+        .find(|param| is_rust_ident(param) && !used.contains(param))
+        .unwrap()
+}
+
+/// Makes a constant name for `name`, which is a valid Rust identifier.
+fn const_name(name: &str) -> String {
+    let mut ident: String = name
+        .to_shouty_snake_case()
+        .chars()
+        .filter(|&c| c.is_alphanumeric() || c == '_')
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_numeric()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+/// The strict and reserved keywords of Rust 2018, which can't be used as
+/// identifiers. None of them is longer than `MAX_KEYWORD_LEN`.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+const MAX_KEYWORD_LEN: usize = 8;
+
+/// Returns whether converting a string to snake case gives a valid Rust
+/// identifier.
+///
+/// The identifier must not be empty, must not start with a digit, must only
+/// contain letters, digits and underscores, and must not be a keyword. The
+/// snake case form is checked as it is computed, without allocating.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::would_be_valid_rust_ident;
+///
+/// assert!(would_be_valid_rust_ident("userName"));
+/// assert!(!would_be_valid_rust_ident("Type"));
+/// assert!(!would_be_valid_rust_ident("2fa"));
+/// ```
+pub fn would_be_valid_rust_ident(s: &str) -> bool {
+    let mut prefix = ['\0'; MAX_KEYWORD_LEN];
+    let mut len = 0;
+
+    for (i, c) in snake_chars(s).enumerate() {
+        if !(c.is_alphanumeric() || c == '_') || (i == 0 && c.is_numeric()) {
+            return false;
+        }
+        if i < MAX_KEYWORD_LEN {
+            prefix[i] = c;
+        }
+        len = i + 1;
+    }
+
+    len != 0
+        && (len > MAX_KEYWORD_LEN
+            || !KEYWORDS
+                .iter()
+                .any(|keyword| keyword.chars().eq(prefix[..len].iter().cloned())))
+}
+
+/// Returns whether `s` is a valid Rust identifier which is not a keyword.
+///
+/// Identifiers start with a letter or an underscore, continue with letters,
+/// digits and underscores, and are not just an underscore.
+pub(crate) fn is_rust_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    let starts_ident = match chars.next() {
+        Some(c) => c.is_alphabetic() || c == '_',
+        None => false,
+    };
+
+    starts_ident
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+        && s != "_"
+        && s != "Self"
+        && !KEYWORDS.contains(&s)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{
+        generate_const_table, snake_case_all_checked, to_generic_param, would_be_valid_rust_ident,
+        Collision, KEYWORDS, MAX_KEYWORD_LEN,
+    };
+    use crate::Case;
+
+    #[test]
+    fn snapshot() {
+        let names = [
+            "user id",
+            "XMLHttpRequest",
+            "2fa_enabled",
+            "don't",
+            "Straße",
+        ];
+        assert_eq!(
+            generate_const_table(&names, Case::LowerCamel).unwrap(),
+            "pub const USER_ID: &str = \"userId\";\n\
+             pub const XML_HTTP_REQUEST: &str = \"xmlHttpRequest\";\n\
+             pub const _2FA_ENABLED: &str = \"2faEnabled\";\n\
+             pub const DONT: &str = \"don't\";\n\
+             pub const STRASSE: &str = \"straße\";\n"
+        );
+    }
+
+    #[test]
+    fn escapes_values() {
+        assert_eq!(
+            generate_const_table(&["say \"hi\""], Case::Title),
+            Ok("pub const SAY_HI: &str = \"Say Hi\";\n".to_owned())
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(generate_const_table(&[], Case::Snake), Ok(String::new()));
+    }
+
+    #[test]
+    fn const_name_collision() {
+        assert_eq!(
+            generate_const_table(&["id", "fooBar", "foo_bar"], Case::Kebab),
+            Err(Collision {
+                first: "fooBar".to_owned(),
+                second: "foo_bar".to_owned(),
+                converted: "FOO_BAR".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn repeated_name() {
+        assert_eq!(
+            generate_const_table(&["fooBar", "fooBar"], Case::Kebab),
+            Ok("pub const FOO_BAR: &str = \"foo-bar\";\n".to_owned())
+        );
+    }
+
+    #[test]
+    fn checked_distinct() {
+        assert_eq!(
+            snake_case_all_checked(&["userId", "user_name", "XMLHttpRequest"]),
+            Ok(vec![
+                "user_id".to_owned(),
+                "user_name".to_owned(),
+                "xml_http_request".to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn checked_collision() {
+        assert_eq!(
+            snake_case_all_checked(vec!["id", "fooBar", "bar", "foo_bar", "Foo Bar"]),
+            Err(Collision {
+                first: "fooBar".to_owned(),
+                second: "foo_bar".to_owned(),
+                converted: "foo_bar".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn checked_duplicates() {
+        assert_eq!(
+            snake_case_all_checked(&["fooBar", "fooBar"]),
+            Ok(vec!["foo_bar".to_owned(), "foo_bar".to_owned()])
+        );
+        assert!(snake_case_all_checked(Vec::<String>::new())
+            .unwrap()
+            .is_empty());
+    }
+
+    macro_rules! g {
+        ($t:ident : $s:expr, [$($used:expr),*] => $param:expr) => {
+            #[test]
+            fn $t() {
+                let used: HashSet<String> = vec![$($used.to_owned()),*].into_iter().collect();
+                assert_eq!(to_generic_param($s, &used), $param)
+            }
+        };
+    }
+
+    g!(generic1: "UserService", [] => "u");
+    g!(generic2: "UserService", ["u"] => "us");
+    g!(generic3: "UserService", ["u", "us"] => "us2");
+    g!(generic4: "UserService", ["u", "us", "us2", "us3"] => "us4");
+    g!(generic5: "XMLHttpRequest", ["x"] => "xhr");
+    g!(generic6: "Item", ["i"] => "i2");
+    g!(generic7: "FooNode", ["f"] => "fn2");
+    g!(generic8: "2d_point", [] => "p");
+    g!(generic9: "???", [] => "t");
+    g!(generic10: "Ärger", [] => "ä");
+
+    #[test]
+    fn generic_params_distinct() {
+        let mut used = HashSet::new();
+        for name in &["UserService", "UserStore", "UserSession", "Unit"] {
+            let param = to_generic_param(name, &used);
+            assert!(used.insert(param));
+        }
+        assert_eq!(used.len(), 4);
+    }
+
+    macro_rules! v {
+        ($t:ident : $s:expr => $valid:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(would_be_valid_rust_ident($s), $valid)
+            }
+        };
+    }
+
+    v!(valid1: "userName" => true);
+    v!(valid2: "Type" => false);
+    v!(valid3: "TYPE" => false);
+    v!(valid4: "type_name" => true);
+    v!(valid5: "2fa" => false);
+    v!(valid6: "fa2" => true);
+    v!(valid7: "don't" => false);
+    v!(valid8: "" => false);
+    v!(valid9: "?!" => false);
+    v!(valid10: "Continue" => false);
+    v!(valid11: "continued" => true);
+    v!(valid12: "straße" => true);
+    v!(valid13: "self" => false);
+
+    #[test]
+    fn max_keyword_len() {
+        assert!(KEYWORDS
+            .iter()
+            .all(|keyword| keyword.len() <= MAX_KEYWORD_LEN));
+    }
+}
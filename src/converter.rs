@@ -0,0 +1,2084 @@
+use std::borrow::Cow;
+
+use crate::codegen::is_rust_ident;
+#[cfg(feature = "unicode-normalization")]
+use crate::Normalization;
+use crate::{fix_final_sigma, Case, Lang, Segmentation, Words};
+
+/// A case converter with options which the `To*Case` traits do not offer.
+///
+/// A `Converter` built with `Converter::new` gives the same results as the
+/// traits, and each option changes that behavior in one specific way.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{Case, Converter, ToSnakeCase};
+///
+/// let converter = Converter::new();
+/// assert_eq!(converter.convert("XMLHttpRequest", Case::Snake), "XMLHttpRequest".to_snake_case());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Converter {
+    segmentation: Segmentation,
+    preserved: Vec<String>,
+    kept_together: Vec<String>,
+    known_acronyms: Vec<String>,
+    /// `known_acronyms` in lowercase, for matching them in any case.
+    lowercase_acronyms: Vec<String>,
+    protected: Vec<(String, String)>,
+    lang: Option<Lang>,
+    acronyms: Acronyms,
+    acronym_style: Option<AcronymStyle>,
+    smart_acronyms: bool,
+    collapse_runs: bool,
+    spell_digits: SpellDigits,
+    merge_single_letters: bool,
+    keep_spacing: bool,
+    keep_dunder: bool,
+    keep_empty_segments: bool,
+    digit_groups: Option<(usize, String)>,
+    #[cfg(feature = "unicode-normalization")]
+    normalization: Option<Normalization>,
+}
+
+/// How a `Converter` splits words which contain digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Digits {
+    /// Digits are part of the word of the letters around them, so
+    /// "file2023backup" is a single word. This is the default.
+    Attach,
+    /// A run of digits is a word of its own, so "file2023backup" is split into
+    /// `file|2023|backup`.
+    Separate,
+    /// A run of letters and the digits after it form a word, so a letter
+    /// after digits starts a new word if more digits follow it: "a1b22c" is
+    /// split into `a1|b22c`, and "mp4v2" into `mp4|v2`. Letters after the last
+    /// digits of a word stay attached, as in "canvas2d".
+    Groups,
+}
+
+impl Default for Digits {
+    fn default() -> Digits {
+        Digits::Attach
+    }
+}
+
+/// Which digits a `Converter` spells out as English words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpellDigits {
+    /// Digits are kept as they are. This is the default.
+    Never,
+    /// The digits at the start of the input are spelled out, so that the
+    /// result does not start with a digit.
+    Leading,
+    /// Every digit is spelled out.
+    All,
+}
+
+impl Default for SpellDigits {
+    fn default() -> SpellDigits {
+        SpellDigits::Never
+    }
+}
+
+/// Which words written in uppercase a `Converter` keeps in uppercase, in the
+/// cases which capitalize words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Acronyms {
+    /// Acronyms are cased like any other word, so "userID" becomes "userId"
+    /// in lowerCamelCase. This is the default.
+    None,
+    /// A final word of two or three uppercase letters stays in uppercase, as
+    /// in the Go and Java conventions, so "userID" stays "userID" in
+    /// lowerCamelCase.
+    Trailing,
+    /// Any word of at least this many uppercase letters stays in uppercase,
+    /// so with a minimum of 3 "HTTPServer" stays "HTTPServer" while "IOError"
+    /// becomes "IoError" in UpperCamelCase. Input written entirely in
+    /// uppercase keeps all of its longer words in uppercase.
+    MinLength(usize),
+}
+
+impl Default for Acronyms {
+    fn default() -> Acronyms {
+        Acronyms::None
+    }
+}
+
+/// How a `Converter` cases acronyms in the cases which capitalize words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AcronymStyle {
+    /// Acronyms are written in uppercase, as in "HTTPServer". This is the
+    /// default.
+    Upper,
+    /// Acronyms are capitalized like other words, as in "HttpServer".
+    Title,
+    /// Acronyms are written in lowercase, as in "baseurl". An acronym which
+    /// starts an UpperCamelCase or Title Case string is still capitalized, as
+    /// in "HttpServer".
+    Lower,
+}
+
+impl Default for AcronymStyle {
+    fn default() -> AcronymStyle {
+        AcronymStyle::Upper
+    }
+}
+
+/// A word of the input, after the converter's options have been applied.
+struct Word<'a> {
+    /// The byte offset of the word in the input.
+    offset: usize,
+    /// The byte offset of the end of the word in the input.
+    end: usize,
+    /// The text of the word, which is a slice of the input from `offset` to
+    /// `end` unless it is a spelled out digit.
+    text: &'a str,
+    kind: WordKind,
+}
+
+/// How a `Word` is cased.
+#[derive(Clone, Copy, PartialEq)]
+enum WordKind {
+    /// The word is cased for the target case.
+    Plain,
+    /// The word is copied to the output as it is.
+    Verbatim,
+    /// The word is kept in uppercase if the target case capitalizes it.
+    Acronym,
+    /// The word follows the previous word without a separator, cased like
+    /// the rest of that word.
+    Joined,
+}
+
+impl<'a> Word<'a> {
+    /// Creates a word for `text`, which is found at `offset` in the input.
+    fn new(offset: usize, text: &'a str, kind: WordKind) -> Word<'a> {
+        Word {
+            offset,
+            end: offset + text.len(),
+            text,
+            kind,
+        }
+    }
+}
+
+impl Converter {
+    /// Creates a converter with the default options.
+    pub fn new() -> Converter {
+        Converter::default()
+    }
+
+    /// Sets whether a change of script (such as from Latin to Cyrillic) is a
+    /// word boundary. Defaults to `false`.
+    ///
+    /// Characters shared between scripts, such as digits, never cause a
+    /// boundary.
+    ///
+    /// This method requires the `unicode-script` feature.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().split_scripts(true);
+    /// assert_eq!(converter.convert("helloмир", Case::Snake), "hello_мир");
+    /// ```
+    #[cfg(feature = "unicode-script")]
+    pub fn split_scripts(mut self, split_scripts: bool) -> Converter {
+        self.segmentation.split_scripts = split_scripts;
+        self
+    }
+
+    /// Sets a character which always splits words, and is dropped from the
+    /// output, just like an underscore. Defaults to `None`.
+    ///
+    /// This gives manual control over splitting when the usual rules don't
+    /// split where they should, or split characters which unicode considers
+    /// part of a word, such as full stops and apostrophes.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().boundary_char(Some('.'));
+    /// assert_eq!(converter.convert("user.firstName", Case::Snake), "user_first_name");
+    /// ```
+    pub fn boundary_char(mut self, boundary_char: Option<char>) -> Converter {
+        self.segmentation.boundary_char = boundary_char;
+        self
+    }
+
+    /// Sets how words containing digits are split. Defaults to
+    /// `Digits::Attach`.
+    ///
+    /// Digits are only split from letters, so numbers such as "3.14" are kept
+    /// together.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter, Digits};
+    ///
+    /// let converter = Converter::new().digits(Digits::Separate);
+    /// assert_eq!(converter.convert("file2023backup", Case::Snake), "file_2023_backup");
+    /// assert_eq!(converter.convert("model2023pro", Case::Title), "Model 2023 Pro");
+    /// ```
+    pub fn digits(mut self, digits: Digits) -> Converter {
+        self.segmentation.digits = digits;
+        self
+    }
+
+    /// Sets whether a number followed by an English ordinal suffix, such as
+    /// "1st" or "2nd", is kept together when digits are separated with
+    /// `Digits::Separate`. Defaults to false.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter, Digits};
+    ///
+    /// let converter = Converter::new().digits(Digits::Separate).ordinals(true);
+    /// assert_eq!(converter.convert("get1stItem", Case::Snake), "get_1st_item");
+    /// ```
+    pub fn ordinals(mut self, ordinals: bool) -> Converter {
+        self.segmentation.ordinals = ordinals;
+        self
+    }
+
+    /// Sets whether every uppercase letter which follows another uppercase
+    /// letter starts a new word, splitting acronyms into their letters.
+    /// Defaults to false.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().split_acronyms(true);
+    /// assert_eq!(converter.convert("XMLHttpRequest", Case::Snake), "x_m_l_http_request");
+    /// ```
+    pub fn split_acronyms(mut self, split_acronyms: bool) -> Converter {
+        self.segmentation.split_acronyms = split_acronyms;
+        self
+    }
+
+    /// Sets which ASCII digits are spelled out as English words, each digit
+    /// becoming a word of its own. Defaults to `SpellDigits::Never`.
+    ///
+    /// Preserved and protected words are not spelled out.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter, SpellDigits};
+    ///
+    /// let converter = Converter::new().spell_digits(SpellDigits::Leading);
+    /// assert_eq!(converter.convert("3dModel", Case::Snake), "three_d_model");
+    /// ```
+    pub fn spell_digits(mut self, spell_digits: SpellDigits) -> Converter {
+        self.spell_digits = spell_digits;
+        self
+    }
+
+    /// Adds words whose casing is kept exactly as written, such as brand
+    /// names.
+    ///
+    /// A preserved word is matched case-sensitively against runs of adjacent
+    /// words in the input, so "GitHub" is found in "myGitHubRepo" even though
+    /// it would otherwise be split into "Git" and "Hub".
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().preserve_words(&["GitHub"]);
+    /// assert_eq!(converter.convert("myGitHubRepo", Case::Snake), "my_GitHub_repo");
+    /// ```
+    pub fn preserve_words<I>(mut self, words: I) -> Converter
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.preserved
+            .extend(words.into_iter().map(|word| word.as_ref().to_owned()));
+        self
+    }
+
+    /// Adds words which are converted as a single word, although they would
+    /// otherwise be split at a change of case.
+    ///
+    /// Like preserved words, these are matched case-sensitively against runs
+    /// of adjacent words in the input, but they are cased like any other
+    /// word.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().keep_together(&["GraphQL"]);
+    /// assert_eq!(converter.convert("GraphQLSchema", Case::Snake), "graphql_schema");
+    /// ```
+    pub fn keep_together<I>(mut self, words: I) -> Converter
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.kept_together
+            .extend(words.into_iter().map(|word| word.as_ref().to_owned()));
+        self
+    }
+
+    /// Adds acronyms which are split from each other when they are written
+    /// next to each other, which the usual rules can't do as there is no
+    /// change of case between them.
+    ///
+    /// Acronyms are matched case-sensitively, so they are usually given in
+    /// uppercase. A word is only split if it is made up entirely of these
+    /// acronyms, optionally followed by a capitalized word, so that "ID" does
+    /// not split "PROVIDER". Where the word can be split in several ways,
+    /// longer acronyms are split off first.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().known_acronyms(&["HTTPS", "FTP"]);
+    /// assert_eq!(converter.convert("HTTPSFTP", Case::Snake), "https_ftp");
+    /// ```
+    pub fn known_acronyms<I>(mut self, acronyms: I) -> Converter
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.known_acronyms.extend(
+            acronyms
+                .into_iter()
+                .map(|acronym| acronym.as_ref().to_owned())
+                .filter(|acronym| !acronym.is_empty()),
+        );
+        self.lowercase_acronyms = self
+            .known_acronyms
+            .iter()
+            .map(|acronym| acronym.to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Adds a pair of delimiters whose contents are copied to the output as
+    /// they are, delimiters included.
+    ///
+    /// The text around a protected region is converted as usual, and the
+    /// region is treated as a word of its own. An opening delimiter without a
+    /// matching closing delimiter is converted as ordinary text.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().protect("{{", "}}");
+    /// assert_eq!(converter.convert("Field {{Id}} Value", Case::Snake), "field_{{Id}}_value");
+    /// ```
+    pub fn protect<S, T>(mut self, open: S, close: T) -> Converter
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        let (open, close) = (open.into(), close.into());
+        if !open.is_empty() && !close.is_empty() {
+            self.protected.push((open, close));
+        }
+        self
+    }
+
+    /// Sets the language whose casing rules are used for words. Defaults to
+    /// no particular language.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter, Lang};
+    ///
+    /// let converter = Converter::new().lang(Lang::Dutch);
+    /// assert_eq!(converter.convert("de ijsberg", Case::Title), "De IJsberg");
+    /// ```
+    pub fn lang(mut self, lang: Lang) -> Converter {
+        self.lang = Some(lang);
+        self
+    }
+
+    /// Sets which words written in uppercase are kept in uppercase when the
+    /// target case capitalizes words. Defaults to `Acronyms::None`.
+    ///
+    /// ```rust
+    /// use heck::{Acronyms, Case, Converter};
+    ///
+    /// let converter = Converter::new().acronyms(Acronyms::Trailing);
+    /// assert_eq!(converter.convert("user_ID", Case::LowerCamel), "userID");
+    /// ```
+    pub fn acronyms(mut self, acronyms: Acronyms) -> Converter {
+        self.acronyms = acronyms;
+        self
+    }
+
+    /// Sets how acronyms are cased when the target case capitalizes words.
+    ///
+    /// This applies to the acronyms selected by `acronyms`, which are
+    /// otherwise kept in uppercase, and to the words which match one of
+    /// `known_acronyms` regardless of their case, which are otherwise cased
+    /// like any other word. As the known acronyms are matched in any case,
+    /// the output is the same however the input wrote them.
+    ///
+    /// ```rust
+    /// use heck::{AcronymStyle, Case, Converter};
+    ///
+    /// let converter = Converter::new()
+    ///     .known_acronyms(&["HTTP"])
+    ///     .acronym_style(AcronymStyle::Upper);
+    /// assert_eq!(converter.convert("httpServer", Case::UpperCamel), "HTTPServer");
+    /// assert_eq!(converter.convert("HttpServer", Case::UpperCamel), "HTTPServer");
+    /// ```
+    pub fn acronym_style(mut self, acronym_style: AcronymStyle) -> Converter {
+        self.acronym_style = Some(acronym_style);
+        self
+    }
+
+    /// Sets whether words which are common programming acronyms, such as
+    /// "HTTP", "URL", "API" and "ID", are cased as acronyms whatever their
+    /// case in the input. Defaults to false.
+    ///
+    /// The acronyms are cased in the style set by `acronym_style`, which
+    /// defaults to uppercase. They are only recognized as whole words, so
+    /// "Identity" is left alone.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().smart_acronyms(true);
+    /// assert_eq!(converter.convert("getHttpApiUrl", Case::LowerCamel), "getHTTPAPIURL");
+    /// assert_eq!(converter.convert("user_id", Case::LowerCamel), "userID");
+    /// ```
+    pub fn smart_acronyms(mut self, smart_acronyms: bool) -> Converter {
+        self.smart_acronyms = smart_acronyms;
+        self
+    }
+
+    /// Sets whether runs of three or more identical characters within a word
+    /// are shortened to two characters. Defaults to false.
+    ///
+    /// This cleans up slugs in which folding has produced unwanted repeats,
+    /// while keeping ordinary doubled letters. Words with a legitimate run of
+    /// three letters, such as the German "Schifffahrt", are shortened too, so
+    /// this is only useful where such words are not expected. Preserved and
+    /// protected words are left as they are.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().collapse_runs(true);
+    /// assert_eq!(converter.convert("Fusssohle strasse", Case::Kebab), "fussohle-strasse");
+    /// ```
+    pub fn collapse_runs(mut self, collapse_runs: bool) -> Converter {
+        self.collapse_runs = collapse_runs;
+        self
+    }
+
+    /// Sets whether a word of a single letter is merged with the word after
+    /// it. Defaults to false.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().merge_single_letters(true);
+    /// assert_eq!(converter.convert("x coordinate", Case::Snake), "xcoordinate");
+    /// assert_eq!(converter.convert("a b c", Case::LowerCamel), "abc");
+    /// ```
+    pub fn merge_single_letters(mut self, merge_single_letters: bool) -> Converter {
+        self.merge_single_letters = merge_single_letters;
+        self
+    }
+
+    /// Sets whether Title Case keeps the whitespace between words as it is,
+    /// instead of separating words with a single space. Defaults to false.
+    ///
+    /// Words which are not separated by whitespace are still separated by a
+    /// single space. Other cases are not affected.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().keep_spacing(true);
+    /// assert_eq!(converter.convert("foo   bar\tbaz", Case::Title), "Foo   Bar\tBaz");
+    /// ```
+    pub fn keep_spacing(mut self, keep_spacing: bool) -> Converter {
+        self.keep_spacing = keep_spacing;
+        self
+    }
+
+    /// Sets whether a "dunder" name, which starts and ends with two
+    /// underscores as in Python's "__init__", keeps its underscores around the
+    /// converted name. Defaults to false.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().keep_dunder(true);
+    /// assert_eq!(converter.convert("__my_var__", Case::LowerCamel), "__myVar__");
+    /// ```
+    pub fn keep_dunder(mut self, keep_dunder: bool) -> Converter {
+        self.keep_dunder = keep_dunder;
+        self
+    }
+
+    /// Sets whether consecutive separators between two words are kept as
+    /// empty segments, instead of being folded into a single separator.
+    /// Defaults to false.
+    ///
+    /// Underscores, hyphens and the `boundary_char` are counted as separators,
+    /// and the output repeats the separator of the target case once for each
+    /// of them. Whitespace is not counted, so "a - b" is still separated once,
+    /// and separators before the first word and after the last word are
+    /// dropped as usual.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new().keep_empty_segments(true);
+    /// assert_eq!(converter.convert("a__b", Case::Snake), "a__b");
+    /// assert_eq!(converter.convert("a__b", Case::Kebab), "a--b");
+    /// ```
+    pub fn keep_empty_segments(mut self, keep_empty_segments: bool) -> Converter {
+        self.keep_empty_segments = keep_empty_segments;
+        self
+    }
+
+    /// Sets whether Title Case groups the digits of numbers for readability,
+    /// inserting `separator` between groups of `size` digits counted from
+    /// the right. Defaults to no grouping, which a size of zero also gives.
+    ///
+    /// Only words made up entirely of ASCII digits are grouped, so this is
+    /// usually combined with `Digits::Separate`, which splits numbers off the
+    /// words around them. Other cases are not affected.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter, Digits};
+    ///
+    /// let converter = Converter::new().digits(Digits::Separate).group_digits(3, " ");
+    /// assert_eq!(converter.convert("balance1000000", Case::Title), "Balance 1 000 000");
+    /// ```
+    pub fn group_digits<S>(mut self, size: usize, separator: S) -> Converter
+    where
+        S: Into<String>,
+    {
+        self.digit_groups = if size == 0 {
+            None
+        } else {
+            Some((size, separator.into()))
+        };
+        self
+    }
+
+    /// Sets a normalization form which the input is normalized to before it
+    /// is converted. Defaults to none.
+    ///
+    /// Combining marks are part of the word of the letter before them, so
+    /// this does not change where words are split, but it makes the output
+    /// the same whether the input was composed or decomposed.
+    ///
+    /// This option requires the `unicode-normalization` feature.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter, Normalization};
+    ///
+    /// let converter = Converter::new().normalization(Normalization::Nfc);
+    /// assert_eq!(converter.convert("Cafe\u{301}Bar", Case::Snake), "caf\u{e9}_bar");
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    pub fn normalization(mut self, normalization: Normalization) -> Converter {
+        self.normalization = Some(normalization);
+        self
+    }
+
+    /// Converts `s` to `case`.
+    ///
+    /// The input may already be in any case, or a mix of several, as it is
+    /// split at every separator as well as at every change of case. This is
+    /// useful when the target case is only known at runtime.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new();
+    /// assert_eq!(converter.convert("Foo_bar", Case::LowerCamel), "fooBar");
+    /// assert_eq!(converter.convert("foo-Bar_baz", Case::ShoutySnake), "FOO_BAR_BAZ");
+    /// ```
+    pub fn convert(&self, s: &str, case: Case) -> String {
+        let s = self.normalize(s);
+        self.render(&s, &self.words(&s), case)
+    }
+
+    /// Converts `s` to each of `cases`, splitting it into words only once.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new();
+    /// assert_eq!(
+    ///     converter.convert_many("user_id", &[Case::Snake, Case::LowerCamel]),
+    ///     vec!["user_id", "userId"],
+    /// );
+    /// ```
+    pub fn convert_many(&self, s: &str, cases: &[Case]) -> Vec<String> {
+        let s = self.normalize(s);
+        let words = self.words(&s);
+        cases
+            .iter()
+            .map(|&case| self.render(&s, &words, case))
+            .collect()
+    }
+
+    /// Converts `s` to `case`, returning `None` if the result is not a valid
+    /// Rust identifier.
+    ///
+    /// Identifiers may contain non-ASCII letters, but no punctuation or
+    /// whitespace, and must not be a keyword, so the result can be used to
+    /// construct an identifier in a procedural macro without panicking.
+    ///
+    /// ```rust
+    /// use heck::{Case, Converter};
+    ///
+    /// let converter = Converter::new();
+    /// assert_eq!(converter.to_ident_string("userName", Case::Snake), Some("user_name".to_owned()));
+    /// assert_eq!(converter.to_ident_string("user name", Case::Title), None);
+    /// assert_eq!(converter.to_ident_string("TYPE", Case::Snake), None);
+    /// ```
+    pub fn to_ident_string(&self, s: &str, case: Case) -> Option<String> {
+        let converted = self.convert(s, case);
+        if is_rust_ident(&converted) {
+            Some(converted)
+        } else {
+            None
+        }
+    }
+
+    /// Applies the normalization form of the converter to `s`.
+    #[cfg(feature = "unicode-normalization")]
+    fn normalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self.normalization {
+            Some(normalization) => normalization.apply(s),
+            None => Cow::Borrowed(s),
+        }
+    }
+
+    #[cfg(not(feature = "unicode-normalization"))]
+    fn normalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        Cow::Borrowed(s)
+    }
+
+    /// Joins `words`, the words of `s`, casing them for `case`.
+    fn render(&self, s: &str, words: &[Word], case: Case) -> String {
+        let mut out = String::new();
+        let dunder = self.keep_dunder && is_dunder(s) && !words.is_empty();
+        if dunder {
+            out.push_str("__");
+        }
+
+        for (index, word) in words.iter().enumerate() {
+            if index != 0 && word.kind != WordKind::Joined {
+                let gap = &s[words[index - 1].end..word.offset];
+                if self.keep_spacing && case == Case::Title {
+                    push_spacing(gap, &mut out);
+                } else if self.keep_empty_segments {
+                    for _ in 0..self.segments_in(gap) {
+                        out.push_str(case.separator());
+                    }
+                } else {
+                    out.push_str(case.separator());
+                }
+            }
+            let start = out.len();
+            match word.kind {
+                WordKind::Plain => match &self.digit_groups {
+                    Some((size, separator))
+                        if case == Case::Title && is_ascii_number(word.text) =>
+                    {
+                        push_digit_groups(word.text, *size, separator, &mut out);
+                        continue;
+                    }
+                    _ => case.push_word(index, word.text, self.lang, &mut out),
+                },
+                WordKind::Verbatim => {
+                    out.push_str(word.text);
+                    continue;
+                }
+                WordKind::Acronym => {
+                    let style = self.acronym_style.unwrap_or_default();
+                    case.push_acronym(index, word.text, style, self.lang, &mut out)
+                }
+                WordKind::Joined => case.push_continuation(word.text, self.lang, &mut out),
+            }
+            fix_final_sigma(s, word.offset, word.text, &mut out, start);
+            if self.collapse_runs {
+                collapse_runs(&mut out, start);
+            }
+        }
+
+        if dunder {
+            out.push_str("__");
+        }
+        out
+    }
+
+    /// Returns the number of separators which `keep_empty_segments` keeps for
+    /// `gap`, the text between two words, which is at least one.
+    fn segments_in(&self, gap: &str) -> usize {
+        gap.chars()
+            .filter(|&c| c == '-' || self.segmentation.is_separator(c))
+            .count()
+            .max(1)
+    }
+
+    /// Splits `s` into words, applying the converter's options.
+    fn words<'a>(&self, s: &'a str) -> Vec<Word<'a>> {
+        let mut words = Vec::new();
+        let mut start = 0;
+
+        let mut protected = ProtectedRegions::new(&self.protected);
+        while let Some((open, close)) = protected.next(s, start) {
+            self.split_into(s, start, open, &mut words);
+            words.push(Word::new(open, &s[open..close], WordKind::Verbatim));
+            start = close;
+        }
+        self.split_into(s, start, s.len(), &mut words);
+
+        if !self.known_acronyms.is_empty() {
+            words = self.split_known_acronyms(words);
+        }
+
+        if !self.preserved.is_empty() {
+            words = merge_runs(s, words, &self.preserved, WordKind::Verbatim);
+        }
+
+        if !self.kept_together.is_empty() {
+            words = merge_runs(s, words, &self.kept_together, WordKind::Plain);
+        }
+
+        if self.spell_digits != SpellDigits::Never {
+            words = self.spell_out_digits(words);
+        }
+
+        if self.merge_single_letters {
+            for i in 1..words.len() {
+                let prev = &words[i - 1];
+                if prev.kind != WordKind::Verbatim
+                    && is_single_letter(prev.text)
+                    && words[i].kind == WordKind::Plain
+                {
+                    words[i].kind = WordKind::Joined;
+                }
+            }
+        }
+
+        match self.acronyms {
+            Acronyms::None => {}
+            Acronyms::Trailing => {
+                if let Some(word) = words.last_mut() {
+                    if word.kind == WordKind::Plain && is_short_acronym(word.text) {
+                        word.kind = WordKind::Acronym;
+                    }
+                }
+            }
+            Acronyms::MinLength(min_len) => {
+                for word in &mut words {
+                    if word.kind == WordKind::Plain && is_acronym(word.text, min_len) {
+                        word.kind = WordKind::Acronym;
+                    }
+                }
+            }
+        }
+
+        if self.acronym_style.is_some() {
+            for word in &mut words {
+                if word.kind == WordKind::Plain && self.is_known_acronym(word.text) {
+                    word.kind = WordKind::Acronym;
+                }
+            }
+        }
+
+        if self.smart_acronyms {
+            for word in &mut words {
+                if word.kind == WordKind::Plain && is_common_acronym(word.text) {
+                    word.kind = WordKind::Acronym;
+                }
+            }
+        }
+
+        words
+    }
+
+    /// Splits plain words which are made up of `known_acronyms`, optionally
+    /// followed by a capitalized word, into those acronyms and that word.
+    ///
+    /// Other words are kept whole, so that an acronym found inside a longer
+    /// word, as "ID" is in "PROVIDER", does not split it.
+    fn split_known_acronyms<'a>(&self, words: Vec<Word<'a>>) -> Vec<Word<'a>> {
+        let mut split = Vec::with_capacity(words.len());
+
+        for word in words {
+            let text = word.text;
+            let tail = capitalized_tail(text);
+            let tiling = match word.kind {
+                WordKind::Plain => self.tile_acronyms(&text[..tail]),
+                _ => None,
+            };
+
+            match tiling {
+                Some(ref lens) if !lens.is_empty() => {
+                    let mut start = 0;
+                    for len in lens {
+                        split.push(Word::new(
+                            word.offset + start,
+                            &text[start..start + len],
+                            WordKind::Plain,
+                        ));
+                        start += len;
+                    }
+                    if tail < text.len() {
+                        split.push(Word::new(
+                            word.offset + tail,
+                            &text[tail..],
+                            WordKind::Plain,
+                        ));
+                    }
+                }
+                _ => split.push(word),
+            }
+        }
+
+        split
+    }
+
+    /// Returns the lengths of `known_acronyms` which, one after another, make
+    /// up the whole of `text`, preferring longer acronyms first, or `None` if
+    /// there are none.
+    fn tile_acronyms(&self, text: &str) -> Option<Vec<usize>> {
+        // Whether `text[i..]` can be made up of acronyms, for each `i`.
+        let mut tiled = vec![false; text.len() + 1];
+        tiled[text.len()] = true;
+        for i in (0..text.len()).rev() {
+            tiled[i] = text.is_char_boundary(i)
+                && self.known_acronyms.iter().any(|acronym| {
+                    text[i..].starts_with(acronym.as_str()) && tiled[i + acronym.len()]
+                });
+        }
+        if !tiled[0] {
+            return None;
+        }
+
+        let mut lens = Vec::new();
+        let mut i = 0;
+        while i < text.len() {
+            let len = self
+                .known_acronyms
+                .iter()
+                .filter(|acronym| text[i..].starts_with(acronym.as_str()))
+                .map(|acronym| acronym.len())
+                .filter(|len| tiled[i + len])
+                .max()?;
+            lens.push(len);
+            i += len;
+        }
+        Some(lens)
+    }
+
+    /// Returns whether `word` is one of `known_acronyms`, ignoring case.
+    fn is_known_acronym(&self, word: &str) -> bool {
+        if word.is_ascii() {
+            self.lowercase_acronyms
+                .iter()
+                .any(|acronym| acronym.eq_ignore_ascii_case(word))
+        } else {
+            let word = word.to_lowercase();
+            self.lowercase_acronyms.contains(&word)
+        }
+    }
+
+    /// Replaces digits in plain words with their English names, as selected
+    /// by `spell_digits`.
+    fn spell_out_digits<'a>(&self, words: Vec<Word<'a>>) -> Vec<Word<'a>> {
+        let mut spelled = Vec::with_capacity(words.len());
+
+        for (index, word) in words.into_iter().enumerate() {
+            if word.kind != WordKind::Plain {
+                spelled.push(word);
+                continue;
+            }
+
+            let mut rest = 0;
+            for (i, c) in word.text.char_indices() {
+                if !c.is_ascii_digit() {
+                    if self.spell_digits == SpellDigits::Leading {
+                        break;
+                    }
+                    continue;
+                }
+                if self.spell_digits == SpellDigits::Leading && (index != 0 || i != rest) {
+                    break;
+                }
+                if rest < i {
+                    spelled.push(Word::new(
+                        word.offset + rest,
+                        &word.text[rest..i],
+                        WordKind::Plain,
+                    ));
+                }
+                spelled.push(Word {
+                    offset: word.offset + i,
+                    end: word.offset + i + 1,
+                    text: DIGIT_NAMES[(c as u8 - b'0') as usize],
+                    kind: WordKind::Plain,
+                });
+                rest = i + 1;
+            }
+            if rest < word.text.len() {
+                spelled.push(Word::new(
+                    word.offset + rest,
+                    &word.text[rest..],
+                    WordKind::Plain,
+                ));
+            }
+        }
+
+        spelled
+    }
+
+    /// Splits the part of `s` between `start` and `end` into words.
+    fn split_into<'a>(&self, s: &'a str, start: usize, end: usize, words: &mut Vec<Word<'a>>) {
+        let split = Words::with_segmentation(&s[start..end], self.segmentation);
+        words.extend(split.map(|(offset, text)| Word::new(start + offset, text, WordKind::Plain)));
+    }
+}
+
+/// The offsets of the opening delimiter of a protected region and of the end
+/// of its closing delimiter.
+type Region = (usize, usize);
+
+/// Finds the protected regions of a string in order, remembering for each
+/// pair of delimiters where its next region is, so that the string is not
+/// searched again from every region.
+struct ProtectedRegions<'a> {
+    /// Each pair of delimiters with the next region found for it, if it has
+    /// been searched for. Pairs which have no more regions are removed.
+    pairs: Vec<(&'a str, &'a str, Option<Region>)>,
+}
+
+impl<'a> ProtectedRegions<'a> {
+    fn new(protected: &'a [(String, String)]) -> ProtectedRegions<'a> {
+        ProtectedRegions {
+            pairs: protected
+                .iter()
+                .map(|(open, close)| (open.as_str(), close.as_str(), None))
+                .collect(),
+        }
+    }
+
+    /// Finds the first protected region of `s` at or after `start`.
+    fn next(&mut self, s: &str, start: usize) -> Option<Region> {
+        for &mut (open, close, ref mut found) in &mut self.pairs {
+            if let Some((open_at, _)) = *found {
+                if open_at >= start {
+                    continue;
+                }
+            }
+            *found = s[start..].find(open).and_then(|open_at| {
+                let content = start + open_at + open.len();
+                let close_at = content + s[content..].find(close)?;
+                Some((start + open_at, close_at + close.len()))
+            });
+        }
+        // An opener which is not closed means that later openers are not
+        // either, so pairs without a region can be dropped.
+        self.pairs.retain(|&(_, _, found)| found.is_some());
+
+        self.pairs.iter().filter_map(|&(_, _, found)| found).min()
+    }
+}
+
+/// Merges runs of adjacent plain words which spell one of `dictionary` into
+/// a single word of `kind`.
+fn merge_runs<'a>(
+    s: &'a str,
+    words: Vec<Word<'a>>,
+    dictionary: &[String],
+    kind: WordKind,
+) -> Vec<Word<'a>> {
+    let mut merged = Vec::with_capacity(words.len());
+    let max_len = dictionary.iter().map(String::len).max().unwrap_or(0);
+    let mut i = 0;
+
+    while i < words.len() {
+        let start = words[i].offset;
+        let mut longest = None;
+        let mut j = i;
+
+        // Runs longer than every word of the dictionary can't match, so the
+        // scan stops there, which keeps long unseparated input linear.
+        while words[j].kind == WordKind::Plain && words[j].end - start <= max_len {
+            let end = words[j].end;
+            if dictionary.iter().any(|word| *word == s[start..end]) {
+                longest = Some(j);
+            }
+            if j + 1 == words.len() || words[j + 1].offset != end {
+                break;
+            }
+            j += 1;
+        }
+
+        match longest {
+            Some(j) => {
+                merged.push(Word::new(start, &s[start..words[j].end], kind));
+                i = j + 1;
+            }
+            None => {
+                merged.push(Word::new(start, words[i].text, words[i].kind));
+                i += 1;
+            }
+        }
+    }
+
+    merged
+}
+
+/// Returns whether `s` starts and ends with two underscores, which do not
+/// overlap.
+fn is_dunder(s: &str) -> bool {
+    s.len() >= 4 && s.starts_with("__") && s.ends_with("__")
+}
+
+/// Pushes the whitespace of `gap`, which is the text between two words, or a
+/// single space if there is none.
+fn push_spacing(gap: &str, out: &mut String) {
+    let len = out.len();
+    out.extend(gap.chars().filter(|c| c.is_whitespace()));
+    if out.len() == len {
+        out.push(' ');
+    }
+}
+
+/// Returns whether `word` is made up entirely of ASCII digits.
+fn is_ascii_number(word: &str) -> bool {
+    !word.is_empty() && word.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Pushes `digits` with `separator` between groups of `size` digits, counted
+/// from the right.
+fn push_digit_groups(digits: &str, size: usize, separator: &str, out: &mut String) {
+    for (i, c) in digits.chars().enumerate() {
+        if i != 0 && (digits.len() - i) % size == 0 {
+            out.push_str(separator);
+        }
+        out.push(c);
+    }
+}
+
+/// The English names of the digits, spelled out by `SpellDigits`.
+const DIGIT_NAMES: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Shortens runs of three or more identical characters in `out` after `start`
+/// to two characters.
+fn collapse_runs(out: &mut String, start: usize) {
+    let word = out.split_off(start);
+    let mut prev = None;
+    let mut run = 0;
+
+    for c in word.chars() {
+        if prev == Some(c) {
+            run += 1;
+        } else {
+            prev = Some(c);
+            run = 1;
+        }
+        if run <= 2 {
+            out.push(c);
+        }
+    }
+}
+
+/// Returns the offset of the capitalized word which ends `text`, an uppercase
+/// letter followed only by lowercase letters, or the length of `text` if it
+/// does not end with one.
+fn capitalized_tail(text: &str) -> usize {
+    let mut tail = text.len();
+    let mut lowercase = false;
+    for (i, c) in text.char_indices().rev() {
+        if c.is_lowercase() {
+            lowercase = true;
+        } else if c.is_uppercase() && lowercase {
+            tail = i;
+            break;
+        } else {
+            break;
+        }
+    }
+    tail
+}
+
+/// Returns whether `word` is a single letter.
+fn is_single_letter(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next().map_or(false, char::is_alphabetic) && chars.next().is_none()
+}
+
+/// Returns whether `word` is an acronym of two or three uppercase letters.
+fn is_short_acronym(word: &str) -> bool {
+    let len = word.chars().count();
+    len >= 2 && len <= 3 && word.chars().all(char::is_uppercase)
+}
+
+/// Returns whether `word` is an acronym of at least `min_len` uppercase
+/// letters.
+fn is_acronym(word: &str, min_len: usize) -> bool {
+    word.chars().count() >= min_len && word.chars().all(char::is_uppercase)
+}
+
+/// The acronyms recognized by `smart_acronyms`.
+const COMMON_ACRONYMS: &[&str] = &[
+    "HTTP", "URL", "API", "ID", "UUID", "IO", "DB", "XML", "JSON", "HTML", "CSS", "SQL",
+];
+
+/// Returns whether `word` is one of `COMMON_ACRONYMS`, ignoring case.
+fn is_common_acronym(word: &str) -> bool {
+    COMMON_ACRONYMS
+        .iter()
+        .any(|acronym| acronym.eq_ignore_ascii_case(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Converter;
+    use crate::{
+        Case, ToKebabCase, ToLowerCamelCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase,
+        ToTitleCase, ToUpperCamelCase,
+    };
+
+    #[test]
+    fn default_matches_traits() {
+        let converter = Converter::new();
+        for s in &[
+            "CamelCase",
+            "This is Human case.",
+            "MixedUP CamelCase, with some Spaces",
+            "XΣXΣ baﬄe",
+            "XMLHttpRequest",
+            "__leading",
+            "",
+        ] {
+            assert_eq!(
+                converter.convert(s, Case::UpperCamel),
+                s.to_upper_camel_case()
+            );
+            assert_eq!(
+                converter.convert(s, Case::LowerCamel),
+                s.to_lower_camel_case()
+            );
+            assert_eq!(converter.convert(s, Case::Snake), s.to_snake_case());
+            assert_eq!(converter.convert(s, Case::Kebab), s.to_kebab_case());
+            assert_eq!(
+                converter.convert(s, Case::ShoutySnake),
+                s.to_shouty_snake_case()
+            );
+            assert_eq!(
+                converter.convert(s, Case::ShoutyKebab),
+                s.to_shouty_kebab_case()
+            );
+            assert_eq!(converter.convert(s, Case::Title), s.to_title_case());
+        }
+    }
+
+    mod already_converted {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    assert_eq!(Converter::new().convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "foo-Bar_baz", UpperCamel => "FooBarBaz");
+        t!(test2: "foo-Bar_baz", LowerCamel => "fooBarBaz");
+        t!(test3: "foo-Bar_baz", Snake => "foo_bar_baz");
+        t!(test4: "foo-Bar_baz", Kebab => "foo-bar-baz");
+        t!(test5: "foo-Bar_baz", ShoutySnake => "FOO_BAR_BAZ");
+        t!(test6: "foo-Bar_baz", ShoutyKebab => "FOO-BAR-BAZ");
+        t!(test7: "foo-Bar_baz", Title => "Foo Bar Baz");
+        t!(test8: "Foo_bar", Snake => "foo_bar");
+        t!(test9: "Foo_bar", UpperCamel => "FooBar");
+        t!(test10: "Foo_Bar-BAZ qux", Kebab => "foo-bar-baz-qux");
+        t!(test11: "FOO_barBaz", Title => "Foo Bar Baz");
+    }
+
+    mod boundary_char {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $c:expr, $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().boundary_char(Some($c));
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: '|', "XML|httprequest", Snake => "xml_httprequest");
+        t!(test2: '|', "X|ML|Http|Request", Snake => "x_ml_http_request");
+        t!(test3: '.', "foo.bar", UpperCamel => "FooBar");
+        t!(test4: '.', "a..b.", Kebab => "a-b");
+        t!(test5: '\'', "O'Brien", Title => "O Brien");
+        t!(test6: '·', "foo·bar", Snake => "foo_bar");
+        t!(test7: 'x', "fooxbar", Snake => "foo_bar");
+
+        #[test]
+        fn none_by_default() {
+            assert_eq!(Converter::new().convert("foo.bar", Case::Snake), "foo.bar");
+        }
+    }
+
+    mod digits {
+        use super::Converter;
+        use crate::{Case, Digits};
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().digits(Digits::Separate);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "file2023backup", Snake => "file_2023_backup");
+        t!(test2: "report2023", Snake => "report_2023");
+        t!(test3: "2023report", Kebab => "2023-report");
+        t!(test4: "model3d", Snake => "model_3_d");
+        t!(test5: "ABC123DEF456", Snake => "abc_123_def_456");
+        t!(test6: "version3.14final", Snake => "version_3.14_final");
+        t!(test7: "fiscalYear2023Q4", UpperCamel => "FiscalYear2023Q4");
+        t!(test8: "2023", Snake => "2023");
+        t!(test9: "model2023pro", Title => "Model 2023 Pro");
+        t!(test10: "2023model", Title => "2023 Model");
+        t!(test11: "2023", Title => "2023");
+        t!(test12: "v2.0beta", Title => "V 2.0 Beta");
+        t!(test13: "model2023pro", LowerCamel => "model2023Pro");
+        t!(test14: "iPhone12ProMax", Kebab => "i-phone-12-pro-max");
+        t!(test15: "GalaxyS23Ultra", Kebab => "galaxy-s-23-ultra");
+        t!(test16: "PS5Pro", Kebab => "ps-5-pro");
+
+        #[test]
+        fn product_slug() {
+            let converter = Converter::new()
+                .digits(Digits::Separate)
+                .keep_together(&["iPhone"]);
+            assert_eq!(
+                converter.convert("iPhone12ProMax", Case::Kebab),
+                "iphone-12-pro-max"
+            );
+        }
+
+        mod groups {
+            use super::Converter;
+            use crate::{Case, Digits};
+
+            macro_rules! t {
+                ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                    #[test]
+                    fn $t() {
+                        let converter = Converter::new().digits(Digits::Groups);
+                        assert_eq!(converter.convert($s1, Case::$case), $s2)
+                    }
+                };
+            }
+
+            t!(test1: "html5Canvas2d", Snake => "html5_canvas2d");
+            t!(test2: "a1b22c", Snake => "a1_b22c");
+            t!(test3: "a1b2c3", Snake => "a1_b2_c3");
+            t!(test4: "mp4v2", Kebab => "mp4-v2");
+            t!(test5: "vec3f", Snake => "vec3f");
+            t!(test6: "2023report", Snake => "2023report");
+            t!(test7: "h264b2Frames", UpperCamel => "H264B2Frames");
+            t!(test8: "x86_64Gl33", Snake => "x86_64_gl33");
+            t!(test9: "file2023backup", Snake => "file2023backup");
+        }
+
+        #[test]
+        fn attach_by_default() {
+            let converter = Converter::new();
+            assert_eq!(
+                converter.convert("file2023backup", Case::Snake),
+                "file2023backup"
+            );
+        }
+    }
+
+    #[test]
+    fn convert_many_matches_convert() {
+        let cases = [
+            Case::UpperCamel,
+            Case::LowerCamel,
+            Case::Snake,
+            Case::Kebab,
+            Case::ShoutySnake,
+            Case::ShoutyKebab,
+            Case::Title,
+        ];
+        let converter = Converter::new().preserve_words(&["iOS"]);
+        for s in &["XMLHttpRequest", "iOS app_version", "ΣΑΣ ΣΑΣ", ""] {
+            let many = converter.convert_many(s, &cases);
+            for (case, converted) in cases.iter().zip(many) {
+                assert_eq!(converted, converter.convert(s, *case));
+            }
+        }
+    }
+
+    mod acronyms {
+        use super::Converter;
+        use crate::{Acronyms, Case};
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().acronyms(Acronyms::Trailing);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "userID", LowerCamel => "userID");
+        t!(test2: "apiURL", LowerCamel => "apiURL");
+        t!(test3: "user_id", LowerCamel => "userId");
+        t!(test4: "userID", UpperCamel => "UserID");
+        t!(test5: "base URL", Title => "Base URL");
+        t!(test6: "userID", Snake => "user_id");
+        t!(test7: "IDName", LowerCamel => "idName");
+        t!(test8: "userHTTPS", LowerCamel => "userHttps");
+        t!(test9: "ID", LowerCamel => "id");
+
+        mod min_length {
+            use super::Converter;
+            use crate::{Acronyms, Case};
+
+            macro_rules! t {
+                ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                    #[test]
+                    fn $t() {
+                        let converter = Converter::new().acronyms(Acronyms::MinLength(3));
+                        assert_eq!(converter.convert($s1, Case::$case), $s2)
+                    }
+                };
+            }
+
+            t!(test1: "HTTPServer", UpperCamel => "HTTPServer");
+            t!(test2: "IOError", UpperCamel => "IoError");
+            t!(test3: "HTTPServer", LowerCamel => "httpServer");
+            t!(test4: "newHTTPServer", LowerCamel => "newHTTPServer");
+            t!(test5: "XML http API", Title => "XML Http API");
+            t!(test6: "HTTPServer", Snake => "http_server");
+            t!(test7: "userID", LowerCamel => "userId");
+            t!(test8: "SHOUTY_CASE", UpperCamel => "SHOUTYCASE");
+        }
+    }
+
+    mod acronym_style {
+        use super::Converter;
+        use crate::{AcronymStyle, Acronyms, Case};
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $style:ident, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new()
+                        .known_acronyms(&["HTTP", "URL"])
+                        .acronym_style(AcronymStyle::$style);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "httpServer", Upper, UpperCamel => "HTTPServer");
+        t!(test2: "httpServer", Title, UpperCamel => "HttpServer");
+        t!(test3: "httpServer", Lower, UpperCamel => "HttpServer");
+        t!(test4: "base_url", Upper, LowerCamel => "baseURL");
+        t!(test5: "base_url", Title, LowerCamel => "baseUrl");
+        t!(test6: "base_url", Lower, LowerCamel => "baseurl");
+        t!(test7: "HTTPServer", Upper, LowerCamel => "httpServer");
+        t!(test8: "the url", Upper, Title => "The URL");
+        t!(test9: "the URL", Lower, Title => "The url");
+        t!(test10: "httpServer", Lower, ShoutySnake => "HTTP_SERVER");
+        t!(test11: "HTTPServer", Upper, Snake => "http_server");
+        t!(test12: "urls", Upper, UpperCamel => "Urls");
+        t!(test13: "url of http", Lower, Title => "Url Of http");
+        t!(test14: "url_server_http", Lower, UpperCamel => "UrlServerhttp");
+
+        #[test]
+        fn applies_to_detected_acronyms() {
+            let converter = Converter::new()
+                .acronyms(Acronyms::Trailing)
+                .acronym_style(AcronymStyle::Lower);
+            assert_eq!(converter.convert("user_ID", Case::UpperCamel), "Userid");
+        }
+
+        #[test]
+        fn input_variants_converge() {
+            for &(style, upper_camel, lower_camel) in &[
+                (AcronymStyle::Upper, "HTTPServer", "httpServerURL"),
+                (AcronymStyle::Title, "HttpServer", "httpServerUrl"),
+                (AcronymStyle::Lower, "HttpServer", "httpServerurl"),
+            ] {
+                let converter = Converter::new()
+                    .known_acronyms(&["HTTP", "URL"])
+                    .acronym_style(style);
+                for s in &["httpServer", "HTTPServer", "HttpServer", "http_server"] {
+                    assert_eq!(converter.convert(s, Case::UpperCamel), upper_camel);
+                    assert_eq!(converter.convert(s, Case::Snake), "http_server");
+                }
+                for s in &["httpServerUrl", "HTTPServerURL", "HttpServerUrl"] {
+                    assert_eq!(converter.convert(s, Case::LowerCamel), lower_camel);
+                }
+            }
+        }
+
+        #[test]
+        fn non_ascii_known_acronyms() {
+            let converter = Converter::new()
+                .known_acronyms(&["ÉTÉ"])
+                .acronym_style(AcronymStyle::Upper);
+            assert_eq!(converter.convert("été_durée", Case::UpperCamel), "ÉTÉDurée");
+        }
+
+        #[test]
+        fn known_acronyms_plain_by_default() {
+            let converter = Converter::new().known_acronyms(&["HTTP"]);
+            assert_eq!(
+                converter.convert("httpServer", Case::UpperCamel),
+                "HttpServer"
+            );
+        }
+    }
+
+    mod smart_acronyms {
+        use super::Converter;
+        use crate::{AcronymStyle, Case};
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().smart_acronyms(true);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "getHttpApiUrl", LowerCamel => "getHTTPAPIURL");
+        t!(test2: "userId", LowerCamel => "userID");
+        t!(test3: "user_id", UpperCamel => "UserID");
+        t!(test4: "uuid_to_json", UpperCamel => "UUIDToJSON");
+        t!(test5: "read_db_io", LowerCamel => "readDBIO");
+        t!(test6: "html_to_xml", UpperCamel => "HTMLToXML");
+        t!(test7: "css_sql", LowerCamel => "cssSQL");
+        t!(test8: "IdentityUrls", UpperCamel => "IdentityUrls");
+        t!(test9: "HTTPServer", Snake => "http_server");
+        t!(test10: "the api", Title => "The API");
+        t!(test11: "userID", ShoutySnake => "USER_ID");
+
+        #[test]
+        fn uses_acronym_style() {
+            let converter = Converter::new()
+                .smart_acronyms(true)
+                .acronym_style(AcronymStyle::Title);
+            assert_eq!(
+                converter.convert("HTTPServer", Case::UpperCamel),
+                "HttpServer"
+            );
+        }
+
+        #[test]
+        fn off_by_default() {
+            assert_eq!(
+                Converter::new().convert("getHttpApiUrl", Case::LowerCamel),
+                "getHttpApiUrl"
+            );
+        }
+    }
+
+    mod collapse_runs {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().collapse_runs(true);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "strasse", Snake => "strasse");
+        t!(test2: "Fusssohle", Kebab => "fussohle");
+        t!(test3: "aaa_bbbb", Snake => "aa_bb");
+        t!(test4: "BRRRR", UpperCamel => "Brr");
+        t!(test5: "balloon keeper", Title => "Balloon Keeper");
+        t!(test6: "foo__bar", Snake => "foo_bar");
+        t!(test7: "xxx yyy", ShoutySnake => "XX_YY");
+
+        #[test]
+        fn off_by_default() {
+            let converter = Converter::new();
+            assert_eq!(converter.convert("Fusssohle", Case::Kebab), "fusssohle");
+        }
+
+        #[test]
+        fn preserved_words_are_kept() {
+            let converter = Converter::new()
+                .collapse_runs(true)
+                .preserve_words(&["WWW"]);
+            assert_eq!(converter.convert("WWW aaa", Case::Snake), "WWW_aa");
+        }
+    }
+
+    mod protect {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().protect("{{", "}}").protect("${", "}");
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "Field {{Id}} Value", Snake => "field_{{Id}}_value");
+        t!(test2: "field_{{id}}_value", UpperCamel => "Field{{id}}Value");
+        t!(test3: "{{ A b }}FooBar", Kebab => "{{ A b }}-foo-bar");
+        t!(test4: "path ${HOME} and {{x}}", ShoutySnake => "PATH_${HOME}_AND_{{x}}");
+        t!(test5: "Unclosed {{Region", Snake => "unclosed_region");
+        t!(test6: "{{a}}{{b}}", Snake => "{{a}}_{{b}}");
+        t!(test7: "${a}} {{b} ${c}", Snake => "${a}_b_${c}");
+        t!(test8: "{{a}} ${b {{c}}", Snake => "{{a}}_${b {{c}");
+        t!(test9: "x{{ ${y} }}z ${", Snake => "x_{{ ${y} }}_z");
+
+        #[test]
+        fn many_regions_with_unclosed_opener() {
+            let converter = Converter::new().protect("{{", "}}").protect("${", "}");
+            let s = format!("{}{{{{", "${x}y".repeat(50_000));
+            let expected = vec!["${x}_y"; 50_000].join("_");
+            assert_eq!(converter.convert(&s, Case::Snake), expected);
+        }
+
+        #[test]
+        fn with_preserved_words() {
+            let converter = Converter::new()
+                .protect("{{", "}}")
+                .preserve_words(&["GitHub"]);
+            assert_eq!(
+                converter.convert("myGitHub{{Repo}}", Case::Snake),
+                "my_GitHub_{{Repo}}"
+            );
+        }
+    }
+
+    mod split_acronyms {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().split_acronyms(true);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "XMLHttpRequest", Snake => "x_m_l_http_request");
+        t!(test2: "XMLHttpRequest", UpperCamel => "XMLHttpRequest");
+        t!(test3: "IOError", Kebab => "i-o-error");
+        t!(test4: "SHOUTY_CASE", Snake => "s_h_o_u_t_y_c_a_s_e");
+        t!(test5: "camelCase", Snake => "camel_case");
+        t!(test6: "HTTP2Server", Snake => "h_t_t_p2_server");
+        t!(test7: "ΑΒΓ", Snake => "α_β_γ");
+        t!(test8: "ΛΌΓΟΣ", Snake => "λ_ό_γ_ο_ς");
+    }
+
+    mod spell_digits {
+        use super::Converter;
+        use crate::{Case, SpellDigits};
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $spell:ident, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().spell_digits(SpellDigits::$spell);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "3dModel", Leading, Snake => "three_d_model");
+        t!(test2: "version2", Leading, Snake => "version2");
+        t!(test3: "version2", All, Snake => "version_two");
+        t!(test4: "3dModel", All, UpperCamel => "ThreeDModel");
+        t!(test5: "42", Leading, Snake => "four_two");
+        t!(test6: "2fa_enabled_v2", Leading, Kebab => "two-fa-enabled-v2");
+        t!(test7: "a1b2", All, Snake => "a_one_b_two");
+        t!(test8: "3dModel", Never, Snake => "3d_model");
+        t!(test9: "__9lives", Leading, LowerCamel => "nineLives");
+
+        #[test]
+        fn preserved_words_are_kept() {
+            let converter = Converter::new()
+                .spell_digits(SpellDigits::All)
+                .preserve_words(&["H264"]);
+            assert_eq!(
+                converter.convert("H264 video1", Case::Snake),
+                "H264_video_one"
+            );
+        }
+    }
+
+    mod keep_dunder {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().keep_dunder(true);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "__init__", LowerCamel => "__init__");
+        t!(test2: "__init__", UpperCamel => "__Init__");
+        t!(test3: "__my_var__", LowerCamel => "__myVar__");
+        t!(test4: "__my_var__", ShoutySnake => "__MY_VAR__");
+        t!(test5: "___init___", Snake => "__init__");
+        t!(test6: "__private", UpperCamel => "Private");
+        t!(test7: "trailing__", UpperCamel => "Trailing");
+        t!(test8: "____", Snake => "");
+        t!(test9: "__", Snake => "");
+
+        #[test]
+        fn dropped_by_default() {
+            let converter = Converter::new();
+            assert_eq!(converter.convert("__init__", Case::UpperCamel), "Init");
+        }
+    }
+
+    mod keep_empty_segments {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().keep_empty_segments(true);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "a__b", Snake => "a__b");
+        t!(test2: "a___b_c", Snake => "a___b_c");
+        t!(test3: "a--b", Kebab => "a--b");
+        t!(test4: "a__b", Kebab => "a--b");
+        t!(test5: "a_-b", ShoutySnake => "A__B");
+        t!(test6: "a - b", Snake => "a_b");
+        t!(test7: "fooBar", Snake => "foo_bar");
+        t!(test8: "__a__b__", Snake => "a__b");
+        t!(test9: "a__b", LowerCamel => "aB");
+        t!(test10: "a__b", Title => "A  B");
+
+        #[test]
+        fn dotted() {
+            let converter = Converter::new()
+                .boundary_char(Some('.'))
+                .keep_empty_segments(true);
+            assert_eq!(converter.convert("a..b.c", Case::Snake), "a__b_c");
+            assert_eq!(converter.convert("a..b.c", Case::Kebab), "a--b-c");
+        }
+
+        #[test]
+        fn folded_by_default() {
+            assert_eq!(Converter::new().convert("a__b", Case::Snake), "a_b");
+        }
+    }
+
+    mod group_digits {
+        use super::Converter;
+        use crate::{Case, Digits};
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $size:expr, $sep:expr => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new()
+                        .digits(Digits::Separate)
+                        .group_digits($size, $sep);
+                    assert_eq!(converter.convert($s1, Case::Title), $s2)
+                }
+            };
+        }
+
+        t!(test1: "balance1000000", 3, " " => "Balance 1 000 000");
+        t!(test2: "value1000000", 3, "\u{2009}" => "Value 1\u{2009}000\u{2009}000");
+        t!(test3: "total_12345678", 4, "," => "Total 1234,5678");
+        t!(test4: "amount999", 3, " " => "Amount 999");
+        t!(test5: "id_1234", 0, " " => "Id 1234");
+        t!(test6: "x10000y", 2, "'" => "X 1'00'00 Y");
+
+        #[test]
+        fn title_case_only() {
+            let converter = Converter::new()
+                .digits(Digits::Separate)
+                .group_digits(3, " ");
+            assert_eq!(
+                converter.convert("balance1000000", Case::Snake),
+                "balance_1000000"
+            );
+        }
+
+        #[test]
+        fn attached_digits_not_grouped() {
+            let converter = Converter::new().group_digits(3, " ");
+            assert_eq!(
+                converter.convert("balance1000000", Case::Title),
+                "Balance1000000"
+            );
+        }
+    }
+
+    mod keep_spacing {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().keep_spacing(true);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "foo   bar", Title => "Foo   Bar");
+        t!(test2: "foo\tbar", Title => "Foo\tBar");
+        t!(test3: "foo \t bar", Title => "Foo \t Bar");
+        t!(test4: "fooBar", Title => "Foo Bar");
+        t!(test5: "foo_bar", Title => "Foo Bar");
+        t!(test6: "foo - bar", Title => "Foo  Bar");
+        t!(test7: "  foo  bar  ", Title => "Foo  Bar");
+        t!(test8: "foo   bar", Snake => "foo_bar");
+
+        #[test]
+        fn spelled_digits() {
+            let converter = Converter::new()
+                .keep_spacing(true)
+                .spell_digits(crate::SpellDigits::All);
+            assert_eq!(converter.convert("a12  b", Case::Title), "A One Two  B");
+        }
+
+        #[test]
+        fn collapsed_by_default() {
+            let converter = Converter::new();
+            assert_eq!(converter.convert("foo   bar", Case::Title), "Foo Bar");
+        }
+    }
+
+    mod known_acronyms {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().known_acronyms(&["HTTP", "HTTPS", "FTP"]);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "HTTPSFTP", Snake => "https_ftp");
+        t!(test2: "HTTPSFTPConnection", Snake => "https_ftp_connection");
+        t!(test3: "FTPHTTP", UpperCamel => "FtpHttp");
+        t!(test4: "HTTPSConnection", Snake => "https_connection");
+        t!(test5: "XFTP", Kebab => "xftp");
+        t!(test6: "FTPX", Kebab => "ftpx");
+        t!(test7: "httpsftp", Snake => "httpsftp");
+        t!(test8: "SFTP", Snake => "sftp");
+        t!(test9: "HTTPFTP_HTTPS", Snake => "http_ftp_https");
+        t!(test10: "FTP", Snake => "ftp");
+
+        #[test]
+        fn word_containing_acronym() {
+            let converter = Converter::new().known_acronyms(&["ID"]);
+            assert_eq!(converter.convert("PROVIDER", Case::Snake), "provider");
+            assert_eq!(converter.convert("VALID_ID", Case::Snake), "valid_id");
+            // Split as without known acronyms, at the change of case.
+            assert_eq!(converter.convert("IDentity", Case::Snake), "i_dentity");
+
+            let converter = Converter::new().known_acronyms(&["IO"]);
+            assert_eq!(
+                converter.convert("RATIO_PRIORITY", Case::Snake),
+                "ratio_priority"
+            );
+        }
+
+        #[test]
+        fn followed_by_capitalized_word() {
+            let converter = Converter::new().known_acronyms(&["IO", "ID"]);
+            assert_eq!(converter.convert("IOIDServer", Case::Snake), "io_id_server");
+            assert_eq!(converter.convert("IOXServer", Case::Snake), "iox_server");
+        }
+
+        #[test]
+        fn backtracks_to_tile() {
+            let converter = Converter::new().known_acronyms(&["HTTPS", "HTTP", "SFTP"]);
+            assert_eq!(converter.convert("HTTPSFTP", Case::Snake), "http_sftp");
+            assert_eq!(converter.convert("HTTPS", Case::Snake), "https");
+        }
+
+        #[test]
+        fn not_split_by_default() {
+            let converter = Converter::new();
+            assert_eq!(converter.convert("HTTPSFTP", Case::Snake), "httpsftp");
+        }
+    }
+
+    mod merge_single_letters {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().merge_single_letters(true);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "a b c", LowerCamel => "abc");
+        t!(test2: "a b c", UpperCamel => "Abc");
+        t!(test3: "x coordinate", Snake => "xcoordinate");
+        t!(test4: "x coordinate", UpperCamel => "Xcoordinate");
+        t!(test5: "get x value", ShoutySnake => "GET_XVALUE");
+        t!(test6: "vitamin a", Title => "Vitamin A");
+        t!(test7: "a 1", Kebab => "a1");
+        t!(test8: "1 a", Kebab => "1-a");
+        t!(test9: "coordinate", Snake => "coordinate");
+
+        #[test]
+        fn off_by_default() {
+            let converter = Converter::new();
+            assert_eq!(converter.convert("a b c", Case::LowerCamel), "aBC");
+            assert_eq!(converter.convert("a b c", Case::UpperCamel), "ABC");
+        }
+    }
+
+    mod ordinals {
+        use super::Converter;
+        use crate::{Case, Digits};
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().digits(Digits::Separate).ordinals(true);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "1stPlace", Snake => "1st_place");
+        t!(test2: "get1stItem", Snake => "get_1st_item");
+        t!(test3: "the 22nd", Kebab => "the-22nd");
+        t!(test4: "3rd_party", UpperCamel => "3rdParty");
+        t!(test5: "4TH_OF_JULY", Snake => "4th_of_july");
+        t!(test6: "1stage", Snake => "1_stage");
+        t!(test7: "5x", Snake => "5_x");
+        t!(test8: "2Nd", Snake => "2_nd");
+        t!(test9: "v2", Snake => "v_2");
+
+        #[test]
+        fn split_without_flag() {
+            let converter = Converter::new().digits(Digits::Separate);
+            assert_eq!(converter.convert("1stPlace", Case::Snake), "1_st_place");
+        }
+    }
+
+    mod preserve_words {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().preserve_words(&["GitHub", "macOS", "iPhone"]);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "myGitHubRepo", Snake => "my_GitHub_repo");
+        t!(test2: "my GitHub repo", UpperCamel => "MyGitHubRepo");
+        t!(test3: "install macOS now", Kebab => "install-macOS-now");
+        t!(test4: "my iPhone case", ShoutySnake => "MY_iPhone_CASE");
+        t!(test5: "iPhone case", LowerCamel => "iPhoneCase");
+        t!(test6: "githubRepo", Snake => "github_repo");
+        t!(test7: "GitHubber", Snake => "git_hubber");
+        t!(test8: "Git Hub", Snake => "git_hub");
+    }
+
+    mod keep_together {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().keep_together(&["GraphQL", "JavaScript"]);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "GraphQLSchema", Snake => "graphql_schema");
+        t!(test2: "my GraphQL api", UpperCamel => "MyGraphqlApi");
+        t!(test3: "JavaScriptEngine", Kebab => "javascript-engine");
+        t!(test4: "GraphQL", ShoutySnake => "GRAPHQL");
+        t!(test5: "Graph QL", Snake => "graph_ql");
+        t!(test6: "graphQl", Snake => "graph_ql");
+
+        #[test]
+        fn split_by_default() {
+            let converter = Converter::new();
+            assert_eq!(
+                converter.convert("GraphQLSchema", Case::Snake),
+                "graph_ql_schema"
+            );
+        }
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    mod normalization {
+        use super::Converter;
+        use crate::{Case, Normalization};
+
+        const COMPOSED: &str = "caf\u{e9}_bar";
+        const DECOMPOSED: &str = "cafe\u{301}_bar";
+
+        #[test]
+        fn nfc() {
+            let converter = Converter::new().normalization(Normalization::Nfc);
+            assert_eq!(converter.convert(COMPOSED, Case::Snake), COMPOSED);
+            assert_eq!(converter.convert(DECOMPOSED, Case::Snake), COMPOSED);
+            assert_eq!(
+                converter.convert("E\u{301}TAT civil", Case::UpperCamel),
+                "\u{c9}tatCivil"
+            );
+        }
+
+        #[test]
+        fn nfd() {
+            let converter = Converter::new().normalization(Normalization::Nfd);
+            assert_eq!(converter.convert(COMPOSED, Case::Snake), DECOMPOSED);
+            assert_eq!(converter.convert(DECOMPOSED, Case::Snake), DECOMPOSED);
+            assert_eq!(
+                converter.convert_many(COMPOSED, &[Case::ShoutyKebab]),
+                ["CAFE\u{301}-BAR"]
+            );
+        }
+
+        #[test]
+        fn off_by_default() {
+            let converter = Converter::new();
+            assert_ne!(
+                converter.convert(COMPOSED, Case::Snake),
+                converter.convert(DECOMPOSED, Case::Snake)
+            );
+        }
+    }
+
+    mod to_ident_string {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new();
+                    let expected: Option<&str> = $s2;
+                    assert_eq!(
+                        converter.to_ident_string($s1, Case::$case),
+                        expected.map(str::to_owned)
+                    )
+                }
+            };
+        }
+
+        t!(test1: "XMLHttpRequest", Snake => Some("xml_http_request"));
+        t!(test2: "Crème Brûlée", UpperCamel => Some("CrèmeBrûlée"));
+        t!(test3: "ΛΌΓΟΣ μου", Snake => Some("λόγος_μου"));
+        t!(test4: "привет мир", ShoutySnake => Some("ПРИВЕТ_МИР"));
+        t!(test5: "?!.", Snake => None);
+        t!(test6: "", Snake => None);
+        t!(test7: "foo bar", Kebab => None);
+        t!(test8: "foo bar", Title => None);
+        t!(test9: "2fa", Snake => None);
+        t!(test10: "Type", Snake => None);
+        t!(test11: "Type", UpperCamel => Some("Type"));
+        t!(test12: "self", UpperCamel => None);
+        t!(test13: "don't", Snake => None);
+    }
+
+    #[cfg(feature = "unicode-script")]
+    mod split_scripts {
+        use super::Converter;
+        use crate::Case;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    let converter = Converter::new().split_scripts(true);
+                    assert_eq!(converter.convert($s1, Case::$case), $s2)
+                }
+            };
+        }
+
+        t!(test1: "helloМир", Snake => "hello_мир");
+        t!(test2: "helloмир", Snake => "hello_мир");
+        t!(test3: "fooБар", UpperCamel => "FooБар");
+        t!(test4: "foo1мир2", Kebab => "foo1-мир2");
+        t!(test5: "αβγabc", ShoutySnake => "ΑΒΓ_ABC");
+        t!(test6: "café", Snake => "café");
+
+        #[test]
+        fn off_by_default() {
+            assert_eq!(
+                Converter::new().convert("helloмир", Case::Snake),
+                "helloмир"
+            );
+        }
+    }
+}
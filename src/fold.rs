@@ -0,0 +1,194 @@
+use std::borrow::Cow;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::ToKebabCase;
+
+/// A Unicode normalization form, which `Converter::normalization` applies to
+/// the input before converting it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Normalization {
+    /// Canonical composition (NFC), so that "e" followed by a combining acute
+    /// accent becomes "é".
+    Nfc,
+    /// Canonical decomposition (NFD), so that "é" becomes "e" followed by a
+    /// combining acute accent.
+    Nfd,
+}
+
+impl Normalization {
+    /// Normalizes `s` to this form, borrowing it if it is ASCII.
+    pub(crate) fn apply(self, s: &str) -> Cow<'_, str> {
+        if s.is_ascii() {
+            return Cow::Borrowed(s);
+        }
+        match self {
+            Normalization::Nfc => Cow::Owned(s.nfc().collect()),
+            Normalization::Nfd => Cow::Owned(s.nfd().collect()),
+        }
+    }
+}
+
+/// What `fold_to_ascii` does with characters which have no ASCII equivalent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Unfoldable {
+    /// Drop the character from the output.
+    Drop,
+    /// Copy the character to the output unchanged.
+    Keep,
+}
+
+/// Transliterates accented letters to their ASCII base letters.
+///
+/// The string is decomposed into its compatibility decomposition (NFKD) and
+/// combining marks are removed, so that "è" becomes "e" and "ﬁ" becomes "fi".
+/// A few letters without a decomposition, such as "ß" and "ø", are mapped to
+/// their conventional ASCII spelling. Any remaining non-ASCII characters are
+/// handled according to `unfoldable`.
+///
+/// The result is meant to be passed to one of the case conversions, which is
+/// useful for generating slugs.
+///
+/// This function requires the `unicode-normalization` feature.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{fold_to_ascii, ToKebabCase, Unfoldable};
+///
+/// let folded = fold_to_ascii("Crème Brûlée", Unfoldable::Drop);
+/// assert_eq!(folded.to_kebab_case(), "creme-brulee");
+/// ```
+pub fn fold_to_ascii(s: &str, unfoldable: Unfoldable) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for c in s.nfkd() {
+        if c.is_ascii() {
+            out.push(c);
+        } else if is_combining_mark(c) {
+            continue;
+        } else if let Some(folded) = fold_char(c) {
+            out.push_str(folded);
+        } else if unfoldable == Unfoldable::Keep {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+/// Converts a string to kebab case, removing accents from letters with an
+/// ASCII base letter.
+///
+/// Unlike `fold_to_ascii`, only the accents are removed: the string is
+/// canonically decomposed (NFD), combining marks after an ASCII letter are
+/// dropped, and everything else, including letters of other scripts, is kept
+/// and composed again. This gives readable slugs without losing whole
+/// scripts.
+///
+/// This function requires the `unicode-normalization` feature.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::to_kebab_case_ascii_folded;
+///
+/// assert_eq!(to_kebab_case_ascii_folded("Ñoño αβ"), "nono-αβ");
+/// ```
+pub fn to_kebab_case_ascii_folded(s: &str) -> String {
+    if s.is_ascii() {
+        return s.to_kebab_case();
+    }
+
+    let mut folded = String::with_capacity(s.len());
+    let mut after_ascii = false;
+    for c in s.nfd() {
+        if is_combining_mark(c) {
+            if after_ascii {
+                continue;
+            }
+        } else {
+            after_ascii = c.is_ascii();
+        }
+        folded.push(c);
+    }
+
+    folded.nfc().collect::<String>().to_kebab_case()
+}
+
+/// ASCII spellings of letters which do not decompose into an ASCII base
+/// letter and combining marks.
+fn fold_char(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'æ' => "ae",
+        'Æ' => "AE",
+        'œ' => "oe",
+        'Œ' => "OE",
+        'ø' => "o",
+        'Ø' => "O",
+        'ł' => "l",
+        'Ł' => "L",
+        'đ' | 'ð' => "d",
+        'Đ' | 'Ð' => "D",
+        'þ' => "th",
+        'Þ' => "Th",
+        'ı' => "i",
+        _ => return None,
+    };
+    Some(folded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fold_to_ascii, to_kebab_case_ascii_folded, Unfoldable};
+    use crate::{ToKebabCase, ToSnakeCase};
+
+    macro_rules! t {
+        ($t:ident : $s1:expr, $mode:ident => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(fold_to_ascii($s1, Unfoldable::$mode), $s2)
+            }
+        };
+    }
+
+    t!(test1: "Crème Brûlée", Drop => "Creme Brulee");
+    t!(test2: "Straße", Drop => "Strasse");
+    t!(test3: "Ærø Łódź", Drop => "AEro Lodz");
+    t!(test4: "ﬁnancial", Drop => "financial");
+    t!(test5: "café αβ", Drop => "cafe ");
+    t!(test6: "café αβ", Keep => "cafe αβ");
+    t!(test7: "plain ascii", Drop => "plain ascii");
+
+    macro_rules! k {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(to_kebab_case_ascii_folded($s1), $s2)
+            }
+        };
+    }
+
+    k!(kebab1: "Ñoño αβ" => "nono-αβ");
+    k!(kebab2: "Crème Brûlée" => "creme-brulee");
+    k!(kebab3: "Cafe\u{301}Bar" => "cafe-bar");
+    k!(kebab4: "ἄλφα àla" => "ἄλφα-ala");
+    k!(kebab5: "むらさき Ça" => "む-ら-さ-き-ca");
+    k!(kebab6: "Straße Łódź" => "straße-łodz");
+    k!(kebab7: "XMLHttpRequest" => "xml-http-request");
+
+    #[test]
+    fn kebab_slug() {
+        let folded = fold_to_ascii("Crème Brûlée", Unfoldable::Drop);
+        assert_eq!(folded.to_kebab_case(), "creme-brulee");
+    }
+
+    #[test]
+    fn snake_keeping_unfoldable() {
+        let folded = fold_to_ascii("Ñoño αβ", Unfoldable::Keep);
+        assert_eq!(folded.to_snake_case(), "nono_αβ");
+    }
+}
@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "unicode-normalization")]
+use crate::{fold_to_ascii, Unfoldable};
+use crate::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
+
+/// Converts a string to a GraphQL field name, in lowerCamelCase.
+///
+/// Acronyms are cased like any other word, following the common GraphQL
+/// style, so "user_ID" becomes "userId". GraphQL names are ASCII, so with the
+/// `unicode-normalization` feature accented letters are first folded to
+/// ASCII, as by `fold_to_ascii`. Any other character which is not allowed is
+/// replaced with an underscore, so "café" becomes "cafe", or "caf_" without
+/// the feature. A name which would start with a digit is prefixed with an
+/// underscore.
+///
+/// This function requires the `graphql` feature.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::to_graphql_field;
+///
+/// assert_eq!(to_graphql_field("user_id"), "userId");
+/// assert_eq!(to_graphql_field("HTTPStatus"), "httpStatus");
+/// ```
+pub fn to_graphql_field(s: &str) -> String {
+    graphql_name(ascii(s).to_lower_camel_case())
+}
+
+/// Converts a string to a GraphQL type name, in UpperCamelCase.
+///
+/// Names follow the same rules as `to_graphql_field`.
+///
+/// This function requires the `graphql` feature.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::to_graphql_type;
+///
+/// assert_eq!(to_graphql_type("user"), "User");
+/// assert_eq!(to_graphql_type("xml_http_request"), "XmlHttpRequest");
+/// ```
+pub fn to_graphql_type(s: &str) -> String {
+    graphql_name(ascii(s).to_upper_camel_case())
+}
+
+/// Converts a string to a GraphQL enum value, in SHOUTY_SNAKE_CASE.
+///
+/// Names follow the same rules as `to_graphql_field`, which also makes them
+/// valid enum values in most JSON schema conventions.
+///
+/// This function requires the `graphql` feature.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::to_enum_value;
+///
+/// assert_eq!(to_enum_value("in progress"), "IN_PROGRESS");
+/// assert_eq!(to_enum_value("2nd place"), "_2ND_PLACE");
+/// ```
+pub fn to_enum_value(s: &str) -> String {
+    graphql_name(ascii(s).to_shouty_snake_case())
+}
+
+/// Folds accented letters of `s` to ASCII, if the `unicode-normalization`
+/// feature is enabled.
+#[cfg(feature = "unicode-normalization")]
+fn ascii(s: &str) -> Cow<'_, str> {
+    if s.is_ascii() {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(fold_to_ascii(s, Unfoldable::Keep))
+    }
+}
+
+#[cfg(not(feature = "unicode-normalization"))]
+fn ascii(s: &str) -> Cow<'_, str> {
+    Cow::Borrowed(s)
+}
+
+/// Makes a converted string a valid GraphQL name, matching
+/// `/[_A-Za-z][_0-9A-Za-z]*/`.
+fn graphql_name(converted: String) -> String {
+    let mut name: String = converted
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_enum_value, to_graphql_field, to_graphql_type};
+
+    macro_rules! t {
+        ($t:ident : $s1:expr => $field:expr, $ty:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(to_graphql_field($s1), $field);
+                assert_eq!(to_graphql_type($s1), $ty);
+            }
+        };
+    }
+
+    t!(test1: "user_id" => "userId", "UserId");
+    t!(test2: "user" => "user", "User");
+    t!(test3: "HTTPStatusCode" => "httpStatusCode", "HttpStatusCode");
+    t!(test4: "created at" => "createdAt", "CreatedAt");
+    t!(test5: "2fa_enabled" => "_2faEnabled", "_2faEnabled");
+    #[cfg(feature = "unicode-normalization")]
+    t!(test6: "café_menu" => "cafeMenu", "CafeMenu");
+    #[cfg(not(feature = "unicode-normalization"))]
+    t!(test6: "café_menu" => "caf_Menu", "Caf_Menu");
+    t!(test7: "東京 tower" => "__Tower", "__Tower");
+
+    mod enum_value {
+        use super::to_enum_value;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    assert_eq!(to_enum_value($s1), $s2)
+                }
+            };
+        }
+
+        t!(test1: "2nd place" => "_2ND_PLACE");
+        t!(test2: "inProgress" => "IN_PROGRESS");
+        t!(test3: "(done!) & dusted?" => "DONE_DUSTED");
+        t!(test4: "n/a" => "N_A");
+        #[cfg(feature = "unicode-normalization")]
+        t!(test5: "café_menu" => "CAFE_MENU");
+        #[cfg(not(feature = "unicode-normalization"))]
+        t!(test5: "café_menu" => "CAF__MENU");
+        t!(test6: "404" => "_404");
+    }
+}
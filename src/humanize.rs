@@ -0,0 +1,50 @@
+use crate::{capitalize, lowercase, transform};
+
+/// Converts a string to a human readable phrase, in sentence case.
+///
+/// The words are separated by spaces, the first word is capitalized and the
+/// rest are lowercased. This is the "humanize" operation of other ecosystems,
+/// such as Rails, and is useful for labels generated from identifiers.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::humanize;
+///
+/// assert_eq!(humanize("getUserProfileData"), "Get user profile data");
+/// ```
+pub fn humanize(s: &str) -> String {
+    transform(
+        s,
+        |word, out| {
+            if out.is_empty() {
+                capitalize(word, out);
+            } else {
+                lowercase(word, out);
+            }
+        },
+        |out| out.push(' '),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::humanize;
+
+    macro_rules! t {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(humanize($s1), $s2)
+            }
+        };
+    }
+
+    t!(test1: "getUserProfileData" => "Get user profile data");
+    t!(test2: "created_at" => "Created at");
+    t!(test3: "SHOUTY_SNAKE_CASE" => "Shouty snake case");
+    t!(test4: "XMLHttpRequest" => "Xml http request");
+    t!(test5: "kebab-case" => "Kebab case");
+    t!(test6: "ΣΑΣ ΣΑΣ" => "Σας σας");
+    t!(test7: "" => "");
+}
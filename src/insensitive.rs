@@ -0,0 +1,107 @@
+use std::hash::{Hash, Hasher};
+
+use crate::{LowercaseChars, Words};
+
+/// A string which hashes and compares by its snake case form.
+///
+/// Strings which differ only in how their words are cased and separated,
+/// such as "FooBar" and "foo_bar", are considered equal. The snake case form is
+/// computed on the fly, without allocating.
+///
+/// ## Example:
+///
+/// ```rust
+/// use std::collections::HashSet;
+///
+/// use heck::CaseInsensitiveSnake;
+///
+/// let mut identifiers = HashSet::new();
+/// identifiers.insert(CaseInsensitiveSnake("FooBar"));
+/// identifiers.insert(CaseInsensitiveSnake("foo_bar"));
+/// assert_eq!(identifiers.len(), 1);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CaseInsensitiveSnake<'a>(pub &'a str);
+
+impl<'a> CaseInsensitiveSnake<'a> {
+    /// The characters of the snake case form of the string.
+    fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        snake_chars(self.0)
+    }
+}
+
+/// The characters of the snake case form of `s`, computed without allocating.
+pub(crate) fn snake_chars(s: &str) -> impl Iterator<Item = char> + '_ {
+    Words::new(s)
+        .enumerate()
+        .flat_map(move |(i, (offset, word))| {
+            let boundary = if i == 0 { None } else { Some('_') };
+            boundary
+                .into_iter()
+                .chain(LowercaseChars::in_context(s, offset, word))
+        })
+}
+
+impl<'a> PartialEq for CaseInsensitiveSnake<'a> {
+    fn eq(&self, other: &CaseInsensitiveSnake) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<'a> Eq for CaseInsensitiveSnake<'a> {}
+
+impl<'a> Hash for CaseInsensitiveSnake<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for c in self.chars() {
+            c.hash(state);
+        }
+        // Like `str`, terminate the hash so that adjacent values in a compound
+        // key can't collide with each other.
+        state.write_u8(0xff);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    use super::CaseInsensitiveSnake;
+
+    fn hash(s: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        CaseInsensitiveSnake(s).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    macro_rules! t {
+        ($t:ident : $s1:expr => $s2:expr, $eq:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(CaseInsensitiveSnake($s1) == CaseInsensitiveSnake($s2), $eq);
+                if $eq {
+                    assert_eq!(hash($s1), hash($s2));
+                }
+            }
+        };
+    }
+
+    t!(test1: "FooBar" => "foo_bar", true);
+    t!(test2: "XMLHttpRequest" => "xml-http-request", true);
+    t!(test3: "SHOUTY_SNAKE_CASE" => "Shouty Snake Case", true);
+    t!(test4: "XΣXΣ baﬄe" => "xσxς_baﬄe", true);
+    t!(test5: "FooBar" => "foobar", false);
+    t!(test6: "foo" => "foo_bar", false);
+    t!(test7: "" => "__", true);
+
+    #[test]
+    fn hash_set() {
+        let mut set = HashSet::new();
+        set.insert(CaseInsensitiveSnake("FooBar"));
+        set.insert(CaseInsensitiveSnake("foo_bar"));
+        set.insert(CaseInsensitiveSnake("fooBar"));
+        set.insert(CaseInsensitiveSnake("foo_baz"));
+        assert_eq!(set.len(), 2);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{lowercase_word, Words};
+
+/// What `to_json_pointer` returns for a string without any words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EmptyPointer {
+    /// The empty pointer "", which refers to the whole document.
+    Document,
+    /// The pointer "/", which refers to the member of the root object whose
+    /// name is empty.
+    Slash,
+}
+
+/// Converts a string to a JSON pointer, with each word as a lowercase
+/// reference token.
+///
+/// Words never contain the characters "~" and "/", so the tokens need no
+/// escaping. `empty` decides the pointer for a string without any words.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{to_json_pointer, EmptyPointer};
+///
+/// assert_eq!(to_json_pointer("FooBar", EmptyPointer::Document), "/foo/bar");
+/// assert_eq!(to_json_pointer("", EmptyPointer::Document), "");
+/// assert_eq!(to_json_pointer("", EmptyPointer::Slash), "/");
+/// ```
+pub fn to_json_pointer(s: &str, empty: EmptyPointer) -> String {
+    let mut out = String::with_capacity(s.len() + 1);
+
+    for (offset, word) in Words::new(s) {
+        out.push('/');
+        lowercase_word(s, offset, word, &mut out);
+    }
+
+    if out.is_empty() && empty == EmptyPointer::Slash {
+        out.push('/');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_json_pointer, EmptyPointer};
+
+    macro_rules! t {
+        ($t:ident : $s1:expr, $empty:ident => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(to_json_pointer($s1, EmptyPointer::$empty), $s2)
+            }
+        };
+    }
+
+    t!(test1: "FooBar", Document => "/foo/bar");
+    t!(test2: "foo_bar baz", Slash => "/foo/bar/baz");
+    t!(test3: "XMLHttpRequest", Document => "/xml/http/request");
+    t!(test4: "a/b~c", Document => "/a/b/c");
+    t!(test5: "ΣΑΣ", Document => "/σας");
+    t!(test6: "", Document => "");
+    t!(test7: "", Slash => "/");
+    t!(test8: "__", Document => "");
+    t!(test9: "__", Slash => "/");
+}
@@ -1,4 +1,4 @@
-use crate::{lowercase, transform};
+use crate::{lowercase, transform, Affix};
 
 /// This trait defines a kebab case conversion.
 ///
@@ -15,12 +15,29 @@ use crate::{lowercase, transform};
 pub trait ToKebabCase: ToOwned {
     /// Convert this type to kebab case.
     fn to_kebab_case(&self) -> Self::Owned;
+
+    /// Convert this type to kebab case, followed by a hyphen and `suffix` as
+    /// it is.
+    ///
+    /// This is useful for slugs which end with a case-sensitive ID. An empty
+    /// suffix is left out, and so is the hyphen if nothing else is left.
+    ///
+    /// ```rust
+    /// use heck::ToKebabCase;
+    ///
+    /// assert_eq!("My Item".to_kebab_case_with_suffix_raw("Ab3Cd"), "my-item-Ab3Cd");
+    /// ```
+    fn to_kebab_case_with_suffix_raw(&self, suffix: &str) -> Self::Owned;
 }
 
 impl ToKebabCase for str {
     fn to_kebab_case(&self) -> Self::Owned {
         transform(self, lowercase, |s| s.push('-'))
     }
+
+    fn to_kebab_case_with_suffix_raw(&self, suffix: &str) -> Self::Owned {
+        Affix::Separate.join(&[&self.to_kebab_case(), suffix], "-")
+    }
 }
 
 #[cfg(test)]
@@ -46,4 +63,34 @@ mod tests {
     t!(test8: "this-contains_ ALLKinds OfWord_Boundaries" => "this-contains-all-kinds-of-word-boundaries");
     t!(test9: "XΣXΣ baﬄe" => "xσxς-baﬄe");
     t!(test10: "XMLHttpRequest" => "xml-http-request");
+    t!(test11: "foo\u{200b}bar" => "foo-bar");
+    t!(test12: "foo\u{200c}bar" => "foobar");
+    t!(test13: "A" => "a");
+    t!(test14: "a" => "a");
+    t!(test15: "5" => "5");
+    t!(test16: "_" => "");
+    t!(test17: "parseXMLData" => "parse-xml-data");
+    t!(test18: "aHTTPServer" => "a-http-server");
+    t!(test19: "foo\r\nbar\tbaz" => "foo-bar-baz");
+    t!(test20: "foo🎉bar🎊baz" => "foo-bar-baz");
+    t!(test21: "foo👍🏽bar" => "foo-bar");
+    t!(test22: "foo👨‍👩‍👧bar" => "foo-bar");
+    t!(test23: "foo🇺🇸bar" => "foo-bar");
+    t!(test24: "🎉foo🎉" => "foo");
+    t!(test25: "🎉" => "");
+
+    macro_rules! r {
+        ($t:ident : $s1:expr, $suffix:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_kebab_case_with_suffix_raw($suffix), $s2)
+            }
+        };
+    }
+
+    r!(raw1: "My Item", "Ab3Cd" => "my-item-Ab3Cd");
+    r!(raw2: "XMLHttpRequest", "X_y Z" => "xml-http-request-X_y Z");
+    r!(raw3: "My Item", "" => "my-item");
+    r!(raw4: "", "Ab3Cd" => "Ab3Cd");
+    r!(raw5: "--post--", "ID" => "post-ID");
 }
@@ -0,0 +1,238 @@
+use crate::{capitalize, lowercase, uppercase};
+
+/// Languages with casing rules which differ from the default unicode casing.
+///
+/// A `Converter` uses the rules of its language, if it has one, when casing
+/// words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Lang {
+    /// Dutch, in which the digraph "ij" is capitalized as a whole, as in
+    /// "IJsland".
+    Dutch,
+    /// Lithuanian, in which a lowercase "i" or "j" keeps its dot when it has
+    /// an accent, as in "i̇̀", following the rules of `SpecialCasing.txt`.
+    Lithuanian,
+    /// Turkish, in which "i" and "ı" are different letters, whose uppercase
+    /// forms are "İ" and "I", following the rules of `SpecialCasing.txt`.
+    Turkish,
+}
+
+/// Like `lowercase`, with the rules of `lang`.
+pub(crate) fn lowercase_in(lang: Option<Lang>, s: &str, out: &mut String) {
+    match lang {
+        Some(Lang::Lithuanian) => lithuanian_lowercase(s, out),
+        Some(Lang::Turkish) => turkish_lowercase(s, out),
+        _ => lowercase(s, out),
+    }
+}
+
+/// Like `uppercase`, with the rules of `lang`.
+pub(crate) fn uppercase_in(lang: Option<Lang>, s: &str, out: &mut String) {
+    match lang {
+        Some(Lang::Lithuanian) => uppercase(&remove_dot_above(s), out),
+        Some(Lang::Turkish) => turkish_uppercase(s, out),
+        _ => uppercase(s, out),
+    }
+}
+
+/// Like `capitalize`, with the rules of `lang`.
+pub(crate) fn capitalize_in(lang: Option<Lang>, s: &str, out: &mut String) {
+    match lang {
+        Some(Lang::Dutch) if starts_with_ij(s) => {
+            out.push_str("IJ");
+            lowercase(&s[2..], out);
+        }
+        Some(Lang::Lithuanian) => {
+            let first_len = s.chars().next().map_or(0, char::len_utf8);
+            let mut rest = &s[first_len..];
+            if is_soft_dotted(&s[..first_len]) && rest.starts_with('\u{307}') {
+                rest = &rest['\u{307}'.len_utf8()..];
+            }
+            capitalize(&s[..first_len], out);
+            lithuanian_lowercase(rest, out);
+        }
+        Some(Lang::Turkish) => {
+            let first_len = s.chars().next().map_or(0, char::len_utf8);
+            turkish_uppercase(&s[..first_len], out);
+            turkish_lowercase(&s[first_len..], out);
+        }
+        _ => capitalize(s, out),
+    }
+}
+
+/// Lowercases `s`, adding a dot above to "i", "j" and "į" where they have an
+/// accent above them.
+fn lithuanian_lowercase(s: &str, out: &mut String) {
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        let end = i + c.len_utf8();
+        let more_above = s[end..].chars().next().map_or(false, is_accent_above);
+        let lower = match c {
+            'I' if more_above => "i\u{307}",
+            'J' if more_above => "j\u{307}",
+            'Į' if more_above => "į\u{307}",
+            'Ì' => "i\u{307}\u{300}",
+            'Í' => "i\u{307}\u{301}",
+            'Ĩ' => "i\u{307}\u{303}",
+            _ => continue,
+        };
+        lowercase(&s[start..i], out);
+        out.push_str(lower);
+        start = end;
+    }
+
+    lowercase(&s[start..], out);
+}
+
+/// Lowercases `s`, mapping "I" to a dotless "ı", and "İ" or "I" followed by a
+/// combining dot above to "i".
+fn turkish_lowercase(s: &str, out: &mut String) {
+    let mut start = 0;
+    let mut chars = s.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        let mut end = i + c.len_utf8();
+        let lower = match c {
+            'I' if s[end..].starts_with('\u{307}') => {
+                chars.next();
+                end += '\u{307}'.len_utf8();
+                'i'
+            }
+            'I' => 'ı',
+            'İ' => 'i',
+            _ => continue,
+        };
+        lowercase(&s[start..i], out);
+        out.push(lower);
+        start = end;
+    }
+
+    lowercase(&s[start..], out);
+}
+
+/// Uppercases `s`, mapping "i" to a dotted "İ".
+fn turkish_uppercase(s: &str, out: &mut String) {
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        if c == 'i' {
+            uppercase(&s[start..i], out);
+            out.push('İ');
+            start = i + c.len_utf8();
+        }
+    }
+
+    uppercase(&s[start..], out);
+}
+
+/// Removes the dots above which follow soft dotted letters, which are not
+/// written when the letters are uppercased.
+fn remove_dot_above(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut prev = None;
+    for c in s.chars() {
+        if c != '\u{307}' || !prev.map_or(false, is_soft_dotted_char) {
+            out.push(c);
+        }
+        prev = Some(c);
+    }
+    out
+}
+
+fn is_soft_dotted(s: &str) -> bool {
+    s.chars().next().map_or(false, is_soft_dotted_char)
+}
+
+fn is_soft_dotted_char(c: char) -> bool {
+    match c {
+        'i' | 'j' | 'į' | 'ɨ' => true,
+        _ => false,
+    }
+}
+
+/// Returns whether `c` is a combining accent placed above the letter before
+/// it, from the combining diacritical marks block.
+fn is_accent_above(c: char) -> bool {
+    match c {
+        '\u{300}'..='\u{314}'
+        | '\u{33D}'..='\u{344}'
+        | '\u{346}'
+        | '\u{34A}'..='\u{34C}'
+        | '\u{350}'..='\u{352}'
+        | '\u{357}'
+        | '\u{35B}'
+        | '\u{363}'..='\u{36F}' => true,
+        _ => false,
+    }
+}
+
+fn starts_with_ij(s: &str) -> bool {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some('i'), Some('j'))
+        | (Some('I'), Some('J'))
+        | (Some('I'), Some('j'))
+        | (Some('i'), Some('J')) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Case, Converter, Lang};
+
+    macro_rules! t {
+        ($t:ident : $lang:ident, $s1:expr, $case:ident => $s2:expr) => {
+            #[test]
+            fn $t() {
+                let converter = Converter::new().lang(Lang::$lang);
+                assert_eq!(converter.convert($s1, Case::$case), $s2)
+            }
+        };
+    }
+
+    t!(dutch1: Dutch, "ijsland", Title => "IJsland");
+    t!(dutch2: Dutch, "de ijsberg", Title => "De IJsberg");
+    t!(dutch3: Dutch, "IJSBERG", Title => "IJsberg");
+    t!(dutch4: Dutch, "het_ijzer", UpperCamel => "HetIJzer");
+    t!(dutch5: Dutch, "ijsland", LowerCamel => "ijsland");
+    t!(dutch6: Dutch, "ijsland", Snake => "ijsland");
+    t!(dutch7: Dutch, "india", Title => "India");
+
+    t!(lithuanian1: Lithuanian, "Ì", Snake => "i\u{307}\u{300}");
+    t!(lithuanian2: Lithuanian, "I\u{300}", Snake => "i\u{307}\u{300}");
+    t!(lithuanian3: Lithuanian, "ĨS_ĮŠ", Snake => "i\u{307}\u{303}s_įš");
+    t!(lithuanian4: Lithuanian, "J\u{301}", Kebab => "j\u{307}\u{301}");
+    t!(lithuanian5: Lithuanian, "IS", Snake => "is");
+    t!(lithuanian6: Lithuanian, "i\u{307}\u{300}", ShoutySnake => "I\u{300}");
+    t!(lithuanian7: Lithuanian, "i\u{307}\u{301}s", Title => "I\u{301}s");
+    t!(lithuanian8: Lithuanian, "ĮÍ", UpperCamel => "Įi\u{307}\u{301}");
+
+    t!(turkish1: Turkish, "title", ShoutySnake => "TİTLE");
+    t!(turkish2: Turkish, "ılık_iş", ShoutyKebab => "ILIK-İŞ");
+    t!(turkish3: Turkish, "TİTLE", Snake => "title");
+    t!(turkish4: Turkish, "ISPARTA", Snake => "ısparta");
+    t!(turkish5: Turkish, "I\u{307}ZMIR", Kebab => "izmır");
+    t!(turkish6: Turkish, "istanbul_izmir", UpperCamel => "İstanbulİzmir");
+    t!(turkish7: Turkish, "ISTANBUL", Title => "Istanbul");
+    t!(turkish8: Turkish, "iş yeri", LowerCamel => "işYeri");
+
+    #[test]
+    fn default_uppercases_i_without_dot() {
+        assert_eq!(
+            Converter::new().convert("title", Case::ShoutySnake),
+            "TITLE"
+        );
+    }
+
+    #[test]
+    fn default_drops_dot_of_accented_i() {
+        assert_eq!(Converter::new().convert("Ì", Case::Snake), "ì");
+    }
+
+    #[test]
+    fn default_capitalizes_first_letter_only() {
+        assert_eq!(Converter::new().convert("ijsland", Case::Title), "Ijsland");
+    }
+}
@@ -12,14 +12,17 @@
 //!
 //! 1. All underscore characters are considered word boundaries.
 //! 2. If an uppercase character is followed by lowercase letters, a word
-//! boundary is considered to be just prior to that uppercase character.
+//!    boundary is considered to be just prior to that uppercase character.
 //! 3. If multiple uppercase characters are consecutive, they are considered to
-//! be within a single word, except that the last will be part of the next word
-//! if it is followed by lowercase characters (see rule 2).
+//!    be within a single word, except that the last will be part of the next
+//!    word if it is followed by lowercase characters (see rule 2).
 //!
 //! That is, "HelloWorld" is segmented `Hello|World` whereas "XMLHttpRequest" is
 //! segmented `XML|Http|Request`.
 //!
+//! A change of case after an apostrophe is not a word boundary, so "O'Brien" is
+//! a single word, whose capital after the apostrophe is kept when capitalized.
+//!
 //! Characters not within words (such as spaces, punctuations, and underscores)
 //! are not included in the output string except as they are a part of the case
 //! being converted to. Multiple adjacent word boundaries (such as a series of
@@ -28,6 +31,21 @@
 //! indicators are dropped, except insofar as CamelCase capitalizes the first
 //! word.
 //!
+//! Emoji and other symbols are not within words either, so they separate the
+//! words around them and are dropped: "foo🎉bar🎊baz" in kebab case is
+//! "foo-bar-baz". This includes emoji made of several characters, such as
+//! flags and sequences joined with a zero width joiner.
+//!
+//! Invisible characters follow the same rules: a zero width space (U+200B) is
+//! a word boundary, while a zero width non-joiner (U+200C) is dropped without
+//! splitting the word it appears in. Other format characters, such as a byte
+//! order mark (U+FEFF), a soft hyphen (U+00AD) or directional marks, are
+//! dropped in the same way.
+//!
+//! A Greek capital sigma is lowercased to a final sigma where it ends a word of
+//! the input, as with `str::to_lowercase`, whether or not the conversion splits
+//! that word further: "λόγοΣ" in snake case is "λόγο_ς".
+//!
 //! ### Cases contained in this library:
 //!
 //! 1. UpperCamelCase
@@ -37,146 +55,767 @@
 //! 5. SHOUTY_SNAKE_CASE
 //! 6. Title Case
 //! 7. SHOUTY-KEBAB-CASE
+//! 8. tOGGLE cASE
+//!
+//! The conversion traits are implemented for `str`, so their methods can be
+//! called on anything which dereferences to `str`, such as `String`,
+//! `Arc<str>` and `Rc<str>`.
+//!
+//! ### Optional features:
+//!
+//! * `graphql`: enables `to_graphql_field`, `to_graphql_type` and
+//!   `to_enum_value`, which convert to valid GraphQL names.
+//! * `rayon`: enables `par_snake_case_all`, which converts many strings in
+//!   parallel.
+//! * `unicode-normalization`: enables `fold_to_ascii`, which transliterates
+//!   accented letters to ASCII before converting,
+//!   `to_kebab_case_ascii_folded`, which removes accents from ASCII letters
+//!   while converting, and `Converter::normalization`, which normalizes the
+//!   input before converting.
+//! * `unicode-script`: enables `Converter::split_scripts`, which splits words
+//!   where the script changes.
 #![deny(missing_docs)]
 #![forbid(unsafe_code)]
 
+mod anchor;
+mod boundary;
+mod camel;
+mod capitalized;
+mod case;
+mod case_fold;
+mod charset;
+mod codegen;
+mod converter;
+#[cfg(feature = "unicode-normalization")]
+mod fold;
+#[cfg(feature = "graphql")]
+mod graphql;
+mod humanize;
+mod insensitive;
+mod json_pointer;
 mod kebab;
+mod lang;
 mod lower_camel;
+#[cfg(feature = "rayon")]
+mod par;
+mod reader;
+mod registry;
+mod segments;
 mod shouty_kebab;
 mod shouty_snake;
 mod snake;
+mod sql;
+mod stats;
 mod title;
+mod toggle;
+mod toml;
 mod upper_camel;
+mod words;
 
+pub use anchor::ToGithubAnchor;
+pub use boundary::{snake_case_with_boundaries, to_flat_module_name, BoundaryKind};
+pub use camel::to_camel_case;
+pub use capitalized::ToCapitalized;
+pub use case::Case;
+pub use case_fold::fold_case;
+pub use charset::{validate_charset, InvalidChar, LossyConversion};
+pub use codegen::{
+    generate_const_table, snake_case_all_checked, to_generic_param, would_be_valid_rust_ident,
+    Collision,
+};
+pub use converter::{AcronymStyle, Acronyms, Converter, Digits, SpellDigits};
+#[cfg(feature = "unicode-normalization")]
+pub use fold::{fold_to_ascii, to_kebab_case_ascii_folded, Normalization, Unfoldable};
+#[cfg(feature = "graphql")]
+pub use graphql::{to_enum_value, to_graphql_field, to_graphql_type};
+pub use humanize::humanize;
+pub use insensitive::CaseInsensitiveSnake;
+pub use json_pointer::{to_json_pointer, EmptyPointer};
 pub use kebab::ToKebabCase;
+pub use lang::Lang;
 pub use lower_camel::ToLowerCamelCase;
+#[cfg(feature = "rayon")]
+pub use par::par_snake_case_all;
+pub use reader::SnakeCaseReader;
+pub use registry::CaseRegistry;
+pub use segments::Segments;
 pub use shouty_kebab::ToShoutyKebabCase;
 pub use shouty_snake::{ToShoutySnakeCase, ToShoutySnekCase};
-pub use snake::{ToSnakeCase, ToSnekCase};
+pub use snake::{Affix, ToSnakeCase, ToSnekCase};
+pub use sql::{to_sql_ident, SqlDialect};
+pub use stats::{conversion_stats, Stats};
 pub use title::ToTitleCase;
+pub use toggle::ToToggleCase;
+pub use toml::to_toml_key;
 pub use upper_camel::{ToPascalCase, ToUpperCamelCase};
+pub use words::{
+    collect_case, join_words, snake_and_camel, snake_case_chars_into, snake_case_chunks,
+    snake_case_into_vec, snake_case_len, to_acronym, to_separated, to_shouty_acronym, word_count,
+    word_spans,
+};
 
-use unicode_segmentation::UnicodeSegmentation;
+use std::char::ToLowercase;
+use std::iter::Peekable;
+use std::str::{CharIndices, Chars};
+
+#[cfg(feature = "unicode-script")]
+use unicode_script::{Script, UnicodeScript};
+use unicode_segmentation::{UnicodeSegmentation, UnicodeWordIndices};
 
 fn transform<F, G>(s: &str, with_word: F, boundary: G) -> String
 where
     F: Fn(&str, &mut String),
     G: Fn(&mut String),
 {
-    /// Tracks the current 'mode' of the transformation algorithm as it scans
-    /// the input string.
-    ///
-    /// The mode is a tri-state which tracks the case of the last cased
-    /// character of the current word. If there is no cased character
-    /// (either lowercase or uppercase) since the previous word boundary,
-    /// than the mode is `Boundary`. If the last cased character is lowercase,
-    /// then the mode is `Lowercase`. Othertherwise, the mode is
-    /// `Uppercase`.
-    #[derive(Clone, Copy, PartialEq)]
-    enum WordMode {
-        /// There have been no lowercase or uppercase characters in the current
-        /// word.
-        Boundary,
-        /// The previous cased character in the current word is lowercase.
-        Lowercase,
-        /// The previous cased character in the current word is uppercase.
-        Uppercase,
-    }
+    join(s, Words::new(s), with_word, boundary)
+}
 
+/// Like `transform`, but for words of `s` which have already been split,
+/// given with their offsets.
+fn join<'a, I, F, G>(s: &str, words: I, with_word: F, boundary: G) -> String
+where
+    I: IntoIterator<Item = (usize, &'a str)>,
+    F: Fn(&str, &mut String),
+    G: Fn(&mut String),
+{
     let mut out = String::new();
     let mut first_word = true;
 
-    for word in s.unicode_words() {
-        let mut char_indices = word.char_indices().peekable();
-        let mut init = 0;
-        let mut mode = WordMode::Boundary;
+    for (offset, word) in words {
+        if !first_word {
+            boundary(&mut out);
+        } else {
+            first_word = false;
+        }
+        let start = out.len();
+        with_word(word, &mut out);
+        fix_final_sigma(s, offset, word, &mut out, start);
+    }
+
+    out
+}
+
+/// Tracks the current 'mode' of the transformation algorithm as it scans
+/// the input string.
+///
+/// The mode is a tri-state which tracks the case of the last cased
+/// character of the current word. If there is no cased character
+/// (either lowercase or uppercase) since the previous word boundary,
+/// than the mode is `Boundary`. If the last cased character is lowercase,
+/// then the mode is `Lowercase`. Othertherwise, the mode is
+/// `Uppercase`.
+#[derive(Clone, Copy, PartialEq)]
+enum WordMode {
+    /// There have been no lowercase or uppercase characters in the current
+    /// word.
+    Boundary,
+    /// The previous cased character in the current word is lowercase.
+    Lowercase,
+    /// The previous cased character in the current word is uppercase.
+    Uppercase,
+}
+
+/// Options which change where `Words` splits a string.
+#[derive(Clone, Copy, Debug, Default)]
+struct Segmentation {
+    /// Whether a change of script within a word is a word boundary.
+    split_scripts: bool,
+    /// A character which, like an underscore, is always a word boundary.
+    boundary_char: Option<char>,
+    digits: Digits,
+    /// Whether consecutive uppercase letters are separate words.
+    split_acronyms: bool,
+    /// Whether separated digits keep an ordinal suffix, as in "1st".
+    ordinals: bool,
+}
+
+impl Segmentation {
+    /// Returns whether `c` is always a word boundary, and is dropped from the
+    /// output.
+    fn is_separator(&self, c: char) -> bool {
+        c == '_' || Some(c) == self.boundary_char
+    }
+
+    /// Returns whether there is a word boundary between `c` and `next` because
+    /// both are uppercase.
+    fn is_acronym_boundary(&self, c: char, next: char) -> bool {
+        self.split_acronyms && c.is_uppercase() && next.is_uppercase()
+    }
+
+    /// Returns whether there is a word boundary between `c` and `next` because
+    /// one of them is a digit. `rest` is the rest of the word, starting with
+    /// `next`.
+    fn is_digit_boundary(&self, c: char, next: char, rest: &str) -> bool {
+        match self.digits {
+            Digits::Attach => false,
+            Digits::Separate => {
+                (c.is_numeric() && next.is_alphabetic() && !(self.ordinals && is_ordinal(rest)))
+                    || (c.is_alphabetic() && next.is_numeric())
+            }
+            Digits::Groups => c.is_numeric() && next.is_alphabetic() && starts_group(rest),
+        }
+    }
+}
+
+/// Returns whether `rest`, which follows a number in a word, starts with
+/// letters which are followed by another number.
+fn starts_group(rest: &str) -> bool {
+    rest.chars()
+        .find(|c| !c.is_alphabetic())
+        .map_or(false, char::is_numeric)
+}
+
+/// Returns whether `rest`, which follows a number in a word, starts with an
+/// English ordinal suffix which ends the word, as in "1st" or "2ND_PLACE".
+fn is_ordinal(rest: &str) -> bool {
+    let suffix = match rest.get(..2) {
+        Some(suffix) => suffix,
+        None => return false,
+    };
+    let is_lowercase = suffix.bytes().all(|b| b.is_ascii_lowercase());
+    let is_uppercase = suffix.bytes().all(|b| b.is_ascii_uppercase());
+    ["st", "nd", "rd", "th"]
+        .iter()
+        .any(|ordinal| suffix.eq_ignore_ascii_case(ordinal))
+        && (is_lowercase || is_uppercase)
+        && match rest[2..].chars().next() {
+            None => true,
+            // "1stPlace" ends the suffix with a change of case
+            Some(after) if after.is_alphabetic() => is_lowercase && after.is_uppercase(),
+            Some(_) => true,
+        }
+}
+
+/// Returns whether `c` belongs to a specific script, rather than being shared
+/// between scripts like digits and punctuation.
+#[cfg(feature = "unicode-script")]
+fn has_script(c: char) -> bool {
+    match c.script() {
+        Script::Common | Script::Inherited | Script::Unknown => false,
+        _ => true,
+    }
+}
+
+#[cfg(not(feature = "unicode-script"))]
+fn has_script(_: char) -> bool {
+    false
+}
+
+/// Returns whether `next` belongs to a different script than `prev`.
+#[cfg(feature = "unicode-script")]
+fn is_script_change(prev: char, next: char) -> bool {
+    has_script(next) && prev.script() != next.script()
+}
+
+#[cfg(not(feature = "unicode-script"))]
+fn is_script_change(_: char, _: char) -> bool {
+    false
+}
+
+/// An iterator over the words of a string, as described in the crate
+/// documentation, yielding the byte offset of each word along with the word.
+struct Words<'a> {
+    segmentation: Segmentation,
+    unicode_words: UnicodeWordIndices<'a>,
+    /// The unicode word currently being split, with its offset in the input.
+    word: Option<(usize, &'a str)>,
+    char_indices: Peekable<CharIndices<'a>>,
+    /// The offset in the current unicode word at which the next word starts.
+    init: usize,
+    mode: WordMode,
+    /// The last character of the current word which belongs to a specific
+    /// script, tracked only when splitting at script changes.
+    script_char: Option<char>,
+}
+
+impl<'a> Words<'a> {
+    fn new(s: &'a str) -> Words<'a> {
+        Words::with_segmentation(s, Segmentation::default())
+    }
+
+    fn with_segmentation(s: &'a str, segmentation: Segmentation) -> Words<'a> {
+        Words {
+            segmentation,
+            unicode_words: s.unicode_word_indices(),
+            word: None,
+            char_indices: "".char_indices().peekable(),
+            init: 0,
+            mode: WordMode::Boundary,
+            script_char: None,
+        }
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        loop {
+            let (offset, word) = match self.word {
+                Some(word) => word,
+                None => {
+                    let word = self.unicode_words.next()?;
+                    self.word = Some(word);
+                    self.char_indices = word.1.char_indices().peekable();
+                    self.init = 0;
+                    self.mode = WordMode::Boundary;
+                    self.script_char = None;
+                    word
+                }
+            };
+
+            let (i, c) = match self.char_indices.next() {
+                Some(next) => next,
+                None => {
+                    self.word = None;
+                    continue;
+                }
+            };
 
-        while let Some((i, c)) = char_indices.next() {
             // Skip underscore characters
-            if c == '_' {
-                if init == i {
-                    init += 1;
+            if self.segmentation.is_separator(c) {
+                if self.init == i {
+                    self.init += c.len_utf8();
                 }
                 continue;
             }
 
-            if let Some(&(next_i, next)) = char_indices.peek() {
+            let init = self.init;
+
+            if self.segmentation.split_scripts && has_script(c) {
+                self.script_char = Some(c);
+            }
+
+            if let Some(&(next_i, next)) = self.char_indices.peek() {
                 // The mode including the current character, assuming the
                 // current character does not result in a word boundary.
                 let next_mode = if c.is_lowercase() {
                     WordMode::Lowercase
                 } else if c.is_uppercase() {
                     WordMode::Uppercase
+                } else if is_apostrophe(c) {
+                    // A change of case after an apostrophe, as in "O'Brien",
+                    // is not a word boundary
+                    WordMode::Boundary
                 } else {
-                    mode
+                    self.mode
                 };
 
+                // Whether next belongs to a different script than the rest of
+                // the current word
+                let script_change = self.segmentation.split_scripts
+                    && self
+                        .script_char
+                        .map_or(false, |script_char| is_script_change(script_char, next));
+
                 // Word boundary after if next is underscore or current is
                 // not uppercase and next is uppercase
-                if next == '_' || (next_mode == WordMode::Lowercase && next.is_uppercase()) {
-                    if !first_word {
-                        boundary(&mut out);
-                    }
-                    with_word(&word[init..next_i], &mut out);
-                    first_word = false;
-                    init = next_i;
-                    mode = WordMode::Boundary;
+                if self.segmentation.is_separator(next)
+                    || self
+                        .segmentation
+                        .is_digit_boundary(c, next, &word[next_i..])
+                    || self.segmentation.is_acronym_boundary(c, next)
+                    || (next_mode == WordMode::Lowercase && next.is_uppercase())
+                    || script_change
+                {
+                    self.init = next_i;
+                    self.mode = WordMode::Boundary;
+                    self.script_char = None;
+                    return Some((offset + init, &word[init..next_i]));
 
                 // Otherwise if current and previous are uppercase and next
                 // is lowercase, word boundary before
-                } else if mode == WordMode::Uppercase && c.is_uppercase() && next.is_lowercase() {
-                    if !first_word {
-                        boundary(&mut out);
+                } else if self.mode == WordMode::Uppercase
+                    && c.is_uppercase()
+                    && next.is_lowercase()
+                {
+                    self.init = i;
+                    self.mode = WordMode::Boundary;
+                    self.script_char = if self.segmentation.split_scripts && has_script(c) {
+                        Some(c)
                     } else {
-                        first_word = false;
-                    }
-                    with_word(&word[init..i], &mut out);
-                    init = i;
-                    mode = WordMode::Boundary;
+                        None
+                    };
+                    return Some((offset + init, &word[init..i]));
 
                 // Otherwise no word boundary, just update the mode
                 } else {
-                    mode = next_mode;
+                    self.mode = next_mode;
                 }
             } else {
                 // Collect trailing characters as a word
-                if !first_word {
-                    boundary(&mut out);
-                } else {
-                    first_word = false;
-                }
-                with_word(&word[init..], &mut out);
-                break;
+                self.word = None;
+                return Some((offset + init, &word[init..]));
             }
         }
     }
+}
 
-    out
+/// Splits `s` at whitespace, punctuation and underscores, but not at changes
+/// of case, yielding the non-empty parts.
+fn split_separators(s: &str) -> impl Iterator<Item = &str> {
+    s.unicode_words()
+        .flat_map(|word| word.split('_'))
+        .filter(|segment| !segment.is_empty())
+}
+
+/// Returns whether `c` is an apostrophe, either ASCII or typographic.
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '\u{2019}'
+}
+
+/// Returns whether `c` is an invisible character which is dropped from the
+/// output without introducing a word boundary.
+///
+/// These are the format characters (general category Cf) which can occur in
+/// text, other than the zero width joiner, which is part of emoji sequences.
+fn is_ignorable(c: char) -> bool {
+    match c {
+        // Soft hyphen
+        '\u{00AD}'
+        // Arabic letter mark
+        | '\u{061C}'
+        // Mongolian vowel separator
+        | '\u{180E}'
+        // Zero width non-joiner, left-to-right and right-to-left marks
+        | '\u{200C}' | '\u{200E}' | '\u{200F}'
+        // Directional embeddings and overrides
+        | '\u{202A}'..='\u{202E}'
+        // Word joiner and invisible operators
+        | '\u{2060}'..='\u{2064}'
+        // Directional isolates and deprecated format characters
+        | '\u{2066}'..='\u{206F}'
+        // Byte order mark
+        | '\u{FEFF}' => true,
+        _ => false,
+    }
+}
+
+/// Returns whether `c` is cased, meaning that it is a letter with case.
+fn is_cased(c: char) -> bool {
+    c.is_lowercase() || c.is_uppercase() || c.to_lowercase().next() != Some(c)
+}
+
+/// Returns whether `c` is ignored when looking for the cased letters around a
+/// sigma.
+///
+/// This approximates the Case_Ignorable property of unicode with the
+/// characters which are likely to occur in words: apostrophes and other
+/// punctuation within words, combining marks, Greek accents and format
+/// characters.
+fn is_case_ignorable(c: char) -> bool {
+    match c {
+        '\''
+        | '.'
+        | ':'
+        | '^'
+        | '`'
+        | '\u{00B4}'
+        | '\u{00B7}'
+        | '\u{0300}'..='\u{036F}'
+        | '\u{0384}'
+        | '\u{0385}'
+        | '\u{0483}'..='\u{0489}'
+        | '\u{200B}'..='\u{200D}'
+        | '\u{2018}'
+        | '\u{2019}'
+        | '\u{2024}'
+        | '\u{2027}' => true,
+        _ => is_ignorable(c),
+    }
+}
+
+/// Returns whether the last character of `s` other than case-ignorable
+/// characters is cased.
+fn ends_with_cased(s: &str) -> bool {
+    s.chars()
+        .rev()
+        .find(|&c| !is_case_ignorable(c))
+        .map_or(false, is_cased)
+}
+
+/// Returns whether the first character of `s` other than case-ignorable
+/// characters is cased.
+fn starts_with_cased(s: &str) -> bool {
+    s.chars()
+        .find(|&c| !is_case_ignorable(c))
+        .map_or(false, is_cased)
+}
+
+/// Returns whether the Σ at byte `i` of `s` is at the end of a word, as
+/// decided by the Final_Sigma rule of unicode: it follows a cased letter and
+/// is not followed by one.
+fn is_final_sigma(s: &str, i: usize) -> bool {
+    ends_with_cased(&s[..i]) && !starts_with_cased(&s[i + 'Σ'.len_utf8()..])
+}
+
+/// Corrects the lowercase sigma at either end of the cased form of `word`,
+/// which has been pushed to `out` after `start`, so that it is final only if
+/// it ends a word of `s`, in which `word` is found at `offset`.
+///
+/// Casing a single word cannot tell whether the word continues in the input,
+/// as when it is split from "λόγοΣ" at the change of case, so this gives the
+/// same sigma as lowercasing the input as a whole.
+fn fix_final_sigma(s: &str, offset: usize, word: &str, out: &mut String, start: usize) {
+    let is_sigma = |c| c == 'σ' || c == 'ς';
+    let sigma = |i| {
+        if is_final_sigma(s, offset + i) {
+            "ς"
+        } else {
+            "σ"
+        }
+    };
+
+    let first = word.len() - word.trim_start_matches(is_ignorable).len();
+    if word[first..].starts_with('Σ') && out[start..].starts_with(is_sigma) {
+        out.replace_range(start..start + 'σ'.len_utf8(), sigma(first));
+    }
+
+    let end = word.trim_end_matches(is_ignorable).len();
+    if word[..end].ends_with('Σ') && out[start..].ends_with(is_sigma) {
+        let last = out.len() - 'σ'.len_utf8();
+        out.replace_range(last.., sigma(end - 'Σ'.len_utf8()));
+    }
 }
 
 fn lowercase(s: &str, out: &mut String) {
-    let mut chars = s.chars().peekable();
-    while let Some(c) = chars.next() {
-        if c == 'Σ' && chars.peek().is_none() {
-            out.push('ς');
+    let cased_before = ends_with_cased(out);
+    out.extend(LowercaseChars::with_context(s, cased_before, false));
+}
+
+/// Lowercases `word`, which is found at `offset` in `s`, with final sigmas
+/// as in the lowercase form of the whole of `s`.
+fn lowercase_word(s: &str, offset: usize, word: &str, out: &mut String) {
+    out.extend(LowercaseChars::in_context(s, offset, word));
+}
+
+/// An iterator over the lowercase form of a word, one character at a time.
+struct LowercaseChars<'a> {
+    chars: Chars<'a>,
+    /// The remaining characters of a character which lowercases into more
+    /// than one character.
+    pending: Option<ToLowercase>,
+    /// Whether the last character before the next one, other than
+    /// case-ignorable characters, is cased.
+    cased_before: bool,
+    /// Whether the first character after the word, other than
+    /// case-ignorable characters, is cased.
+    cased_after: bool,
+}
+
+impl<'a> LowercaseChars<'a> {
+    /// Lowercases `word`, which is found at `offset` in `s`, deciding whether
+    /// a sigma is final from the text around the word.
+    fn in_context(s: &'a str, offset: usize, word: &'a str) -> LowercaseChars<'a> {
+        LowercaseChars::with_context(
+            word,
+            ends_with_cased(&s[..offset]),
+            starts_with_cased(&s[offset + word.len()..]),
+        )
+    }
+
+    fn with_context(s: &'a str, cased_before: bool, cased_after: bool) -> LowercaseChars<'a> {
+        LowercaseChars {
+            chars: s.chars(),
+            pending: None,
+            cased_before,
+            cased_after,
+        }
+    }
+}
+
+impl<'a> Iterator for LowercaseChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(c) = self.pending.as_mut().and_then(Iterator::next) {
+            return Some(c);
+        }
+
+        let c = self.chars.find(|&c| !is_ignorable(c))?;
+        let cased_before = self.cased_before;
+        if !is_case_ignorable(c) {
+            self.cased_before = is_cased(c);
+        }
+
+        if c == 'Σ' {
+            let cased_after = match self.chars.clone().find(|&c| !is_case_ignorable(c)) {
+                Some(next) => is_cased(next),
+                None => self.cased_after,
+            };
+            Some(if cased_before && !cased_after {
+                'ς'
+            } else {
+                'σ'
+            })
         } else {
-            out.extend(c.to_lowercase());
+            let mut lower = c.to_lowercase();
+            let first = lower.next();
+            self.pending = Some(lower);
+            first
         }
     }
 }
 
 fn uppercase(s: &str, out: &mut String) {
-    for c in s.chars() {
+    for c in s.chars().filter(|&c| !is_ignorable(c)) {
         out.extend(c.to_uppercase())
     }
 }
 
 fn capitalize(s: &str, out: &mut String) {
-    let mut char_indices = s.char_indices();
-    if let Some((_, c)) = char_indices.next() {
-        out.extend(c.to_uppercase());
-        if let Some((i, _)) = char_indices.next() {
-            lowercase(&s[i..], out);
+    // Words which are already capitalized are copied as they are, which is
+    // much faster than recasing them. Only ASCII is checked, as recasing
+    // other characters may change them even when they look capitalized.
+    if is_capitalized_ascii(s) {
+        out.push_str(s);
+    } else {
+        capitalize_chars(s, out);
+    }
+}
+
+/// Returns whether `s` is ASCII and capitalizing it leaves it unchanged.
+fn is_capitalized_ascii(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    bytes
+        .next()
+        .map_or(true, |b| b.is_ascii() && !b.is_ascii_lowercase())
+        && bytes.all(|b| b.is_ascii() && !b.is_ascii_uppercase())
+}
+
+fn capitalize_chars(mut s: &str, out: &mut String) {
+    // Each capital after an apostrophe starts the next part of the word. This
+    // is a loop rather than recursion so that long words can't overflow the
+    // stack.
+    loop {
+        let mut char_indices = s.char_indices().skip_while(|&(_, c)| is_ignorable(c));
+        match char_indices.next() {
+            Some((_, c)) => titlecase(c, out),
+            None => return,
+        }
+        let rest = match char_indices.next() {
+            Some((i, _)) => &s[i..],
+            None => return,
+        };
+        match capitalized_after_apostrophe(rest) {
+            Some(j) => {
+                lowercase(&rest[..j], out);
+                s = &rest[j..];
+            }
+            None => return lowercase(rest, out),
+        }
+    }
+}
+
+/// Finds the first capital letter in `s` which follows an apostrophe and is
+/// followed by a lowercase letter, as in "O'Brien", and so remains capital.
+fn capitalized_after_apostrophe(s: &str) -> Option<usize> {
+    let mut chars = s.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        if is_apostrophe(c) {
+            if let Some(&(i, next)) = chars.peek() {
+                let after = s[i + next.len_utf8()..].chars().next();
+                if next.is_uppercase() && after.map_or(false, char::is_lowercase) {
+                    return Some(i);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Pushes the titlecase form of `c`, which for a handful of characters (such as
+/// the Latin digraphs and ligatures) differs from its uppercase form.
+fn titlecase(c: char, out: &mut String) {
+    match c {
+        'Ǆ' | 'ǅ' | 'ǆ' => out.push('ǅ'),
+        'Ǉ' | 'ǈ' | 'ǉ' => out.push('ǈ'),
+        'Ǌ' | 'ǋ' | 'ǌ' => out.push('ǋ'),
+        'Ǳ' | 'ǲ' | 'ǳ' => out.push('ǲ'),
+        'ß' => out.push_str("Ss"),
+        'ﬀ' => out.push_str("Ff"),
+        'ﬁ' => out.push_str("Fi"),
+        'ﬂ' => out.push_str("Fl"),
+        'ﬃ' => out.push_str("Ffi"),
+        'ﬄ' => out.push_str("Ffl"),
+        'ﬅ' | 'ﬆ' => out.push_str("St"),
+        'և' => out.push_str("Եւ"),
+        'ﬓ' => out.push_str("Մն"),
+        'ﬔ' => out.push_str("Մե"),
+        'ﬕ' => out.push_str("Մի"),
+        'ﬖ' => out.push_str("Վն"),
+        'ﬗ' => out.push_str("Մխ"),
+        _ => out.extend(c.to_uppercase()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{capitalize, capitalize_chars};
+    use crate::{Case, Converter, ToSnakeCase};
+
+    #[test]
+    fn capitalize_fast_path_matches() {
+        for word in &[
+            "", "Foo", "foo", "FOO", "fOO", "F", "f", "2foo", "2Foo", "Foo2", "Ab-c", "Ǆemal",
+            "Σας", "Über", "ÜBER", "Ǉa", "ﬁle", "Straße",
+        ] {
+            let mut fast = String::new();
+            capitalize(word, &mut fast);
+            let mut chars = String::new();
+            capitalize_chars(word, &mut chars);
+            assert_eq!(fast, chars, "{:?}", word);
         }
     }
+
+    #[test]
+    fn final_sigma_matches_lowercase() {
+        let split = Converter::new().split_acronyms(true);
+        for s in &[
+            "ΛΌΓΟΣ",
+            "λόγοΣ",
+            "ΛΌΓΟΣ ΣΑΣ",
+            "ΟΔΟΣΚαι",
+            "ΟΔΟΣ_ΚΑΙ",
+            "Σ",
+            "Σ_Α",
+            "ΑΣ1Β",
+            "ΑΣ\u{301}",
+            "XΣXΣ baﬄe",
+        ] {
+            let lower = s.to_lowercase().replace(|c| c == '_' || c == ' ', "");
+            assert_eq!(s.to_snake_case().replace('_', ""), lower, "{:?}", s);
+            let converted = split.convert(s, Case::Snake);
+            assert_eq!(converted.replace('_', ""), lower, "{:?}", s);
+        }
+    }
+
+    /// Conversions are linear in the length of the input, so these finish
+    /// quickly even though the inputs are hundreds of kilobytes long, and
+    /// don't recurse once per word or segment.
+    #[test]
+    fn long_input() {
+        use crate::{ToTitleCase, ToUpperCamelCase};
+
+        let camel = "FooBarBaz".repeat(50_000);
+        assert_eq!(
+            camel.to_snake_case(),
+            "foo_bar_baz_".repeat(50_000).trim_end_matches('_')
+        );
+
+        let converter = Converter::new()
+            .preserve_words(&["iOS"])
+            .keep_together(&["GraphQL"])
+            .known_acronyms(&["HTTP"]);
+        assert_eq!(
+            converter.convert(&camel, Case::UpperCamel),
+            camel.to_upper_camel_case()
+        );
+
+        let apostrophes = format!("x{}", "'Ab".repeat(100_000));
+        assert_eq!(
+            apostrophes.to_title_case(),
+            format!("X{}", "'Ab".repeat(100_000))
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{capitalize, lowercase, transform};
+use crate::to_camel_case;
 
 /// This trait defines a lower camel case conversion.
 ///
@@ -20,17 +20,7 @@ pub trait ToLowerCamelCase: ToOwned {
 
 impl ToLowerCamelCase for str {
     fn to_lower_camel_case(&self) -> String {
-        transform(
-            self,
-            |s, out| {
-                if out.is_empty() {
-                    lowercase(s, out);
-                } else {
-                    capitalize(s, out)
-                }
-            },
-            |_| {},
-        )
+        to_camel_case(self, false)
     }
 }
 
@@ -57,5 +47,14 @@ mod tests {
     t!(test8: "this-contains_ ALLKinds OfWord_Boundaries" => "thisContainsAllKindsOfWordBoundaries");
     t!(test9: "XΣXΣ baﬄe" => "xσxςBaﬄe");
     t!(test10: "XMLHttpRequest" => "xmlHttpRequest");
+    t!(test11: "foo\u{200b}bar" => "fooBar");
+    t!(test12: "foo\u{200c}bar" => "foobar");
+    t!(test13: "A" => "a");
+    t!(test14: "a" => "a");
+    t!(test15: "5" => "5");
+    t!(test16: "_" => "");
+    t!(test17: "foo\r\nbar\tbaz" => "fooBarBaz");
+    t!(test18: "ΣΑΣ_ΟΔΟΣ" => "σαςΟδος");
+    t!(test19: "foo🎉bar❤️baz" => "fooBarBaz");
     // TODO unicode tests
 }
@@ -0,0 +1,50 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::ToSnakeCase;
+
+/// Converts every item to snake case in parallel, keeping the order of the
+/// items.
+///
+/// Each conversion is independent, so this scales with the number of threads
+/// in the rayon thread pool when converting large numbers of identifiers.
+///
+/// This function requires the `rayon` feature.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::par_snake_case_all;
+///
+/// let names = vec!["FooBar", "XMLHttpRequest"];
+/// assert_eq!(par_snake_case_all(names), vec!["foo_bar", "xml_http_request"]);
+/// ```
+pub fn par_snake_case_all<I>(items: I) -> Vec<String>
+where
+    I: IntoParallelIterator,
+    I::Item: AsRef<str>,
+{
+    items
+        .into_par_iter()
+        .map(|item| item.as_ref().to_snake_case())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::par_snake_case_all;
+    use crate::ToSnakeCase;
+
+    #[test]
+    fn matches_sequential() {
+        let names: Vec<String> = (0..10_000)
+            .map(|i| format!("SomeIdentifier{}WithXMLInside", i))
+            .collect();
+        let sequential: Vec<String> = names.iter().map(|name| name.to_snake_case()).collect();
+        assert_eq!(par_snake_case_all(&names), sequential);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(par_snake_case_all(Vec::<&str>::new()).is_empty());
+    }
+}
@@ -0,0 +1,205 @@
+use std::io::{self, Read};
+use std::str;
+
+use crate::ToSnakeCase;
+
+/// The number of bytes read from the source at a time.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// A reader which converts the UTF-8 text of another reader to snake case as
+/// it is read.
+///
+/// Words never span whitespace, so the source is converted one run of text
+/// at a time, each ending at whitespace. Only the current run is buffered,
+/// which keeps memory use bounded for text with regular whitespace. A source
+/// which is not valid UTF-8 results in an error of kind
+/// `io::ErrorKind::InvalidData`.
+///
+/// ## Example:
+///
+/// ```rust
+/// use std::io::Read;
+///
+/// use heck::SnakeCaseReader;
+///
+/// let mut reader = SnakeCaseReader::new("We carry a new world here.".as_bytes());
+/// let mut out = String::new();
+/// reader.read_to_string(&mut out).unwrap();
+/// assert_eq!(out, "we_carry_a_new_world_here");
+/// ```
+#[derive(Debug)]
+pub struct SnakeCaseReader<R> {
+    inner: R,
+    /// Source bytes which have not been converted yet.
+    input: Vec<u8>,
+    /// Converted bytes, of which those before `pos` have been read.
+    output: Vec<u8>,
+    pos: usize,
+    /// Whether any word has been converted, so that the next one needs a
+    /// separator.
+    wrote_word: bool,
+    eof: bool,
+}
+
+impl<R: Read> SnakeCaseReader<R> {
+    /// Creates a reader which converts the text of `inner` to snake case.
+    pub fn new(inner: R) -> SnakeCaseReader<R> {
+        SnakeCaseReader {
+            inner,
+            input: Vec::new(),
+            output: Vec::new(),
+            pos: 0,
+            wrote_word: false,
+            eof: false,
+        }
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads from the source until some converted output is available or the
+    /// source is exhausted.
+    fn fill(&mut self) -> io::Result<()> {
+        let len = self.input.len();
+        self.input.resize(len + CHUNK_SIZE, 0);
+        let read = match self.inner.read(&mut self.input[len..]) {
+            Ok(read) => read,
+            Err(err) => {
+                self.input.truncate(len);
+                return Err(err);
+            }
+        };
+        self.input.truncate(len + read);
+
+        if read == 0 {
+            self.eof = true;
+            let input = std::mem::replace(&mut self.input, Vec::new());
+            self.convert(&input)
+        } else if let Some(end) = self.input[len..].iter().rposition(u8::is_ascii_whitespace) {
+            // The buffered input never contains whitespace, so only the bytes
+            // just read need to be searched. ASCII whitespace bytes never
+            // occur within the encoding of another character, so this is a
+            // character boundary.
+            let rest = self.input.split_off(len + end + 1);
+            let input = std::mem::replace(&mut self.input, rest);
+            self.convert(&input)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Converts a run of source text which ends at a word boundary.
+    fn convert(&mut self, input: &[u8]) -> io::Result<()> {
+        let text =
+            str::from_utf8(input).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let converted = text.to_snake_case();
+
+        self.output.clear();
+        self.pos = 0;
+        if !converted.is_empty() {
+            if self.wrote_word {
+                self.output.push(b'_');
+            }
+            self.output.extend_from_slice(converted.as_bytes());
+            self.wrote_word = true;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for SnakeCaseReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pos == self.output.len() {
+            if self.eof {
+                return Ok(0);
+            }
+            self.fill()?;
+        }
+
+        let available = &self.output[self.pos..];
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.pos += len;
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use super::SnakeCaseReader;
+    use crate::ToSnakeCase;
+
+    /// A reader which returns at most `step` bytes at a time.
+    struct Trickle<'a> {
+        bytes: &'a [u8],
+        step: usize,
+    }
+
+    impl<'a> Read for Trickle<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.step.min(buf.len()).min(self.bytes.len());
+            buf[..len].copy_from_slice(&self.bytes[..len]);
+            self.bytes = &self.bytes[len..];
+            Ok(len)
+        }
+    }
+
+    fn read_through(s: &str, step: usize, buf_size: usize) -> String {
+        let mut reader = SnakeCaseReader::new(Trickle {
+            bytes: s.as_bytes(),
+            step,
+        });
+        let mut out = Vec::new();
+        let mut buf = vec![0; buf_size];
+        loop {
+            let read = reader.read(&mut buf).unwrap();
+            if read == 0 {
+                break;
+            }
+            out.extend_from_slice(&buf[..read]);
+        }
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn matches_to_snake_case() {
+        for s in &[
+            "CamelCase",
+            "This is Human case.",
+            "MixedUP CamelCase, with some Spaces",
+            "  leading and trailing  ",
+            "XΣXΣ baﬄe ΛΌΓΟΣ ΣΑΣ",
+            "foo🎉bar\n\nXMLHttpRequest\tagain",
+            "",
+        ] {
+            for &step in &[1, 2, 3, 7, 100] {
+                for &buf_size in &[1, 2, 5, 64] {
+                    assert_eq!(read_through(s, step, buf_size), s.to_snake_case());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn long_input() {
+        let s = "SomeWords and_more-words ".repeat(2000);
+        assert_eq!(read_through(&s, 1000, 333), s.to_snake_case());
+    }
+
+    #[test]
+    fn long_input_without_whitespace() {
+        let s = "SomeWords_and-more".repeat(20_000);
+        assert_eq!(read_through(&s, 1000, 333), s.to_snake_case());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut reader = SnakeCaseReader::new(&b"foo \xff bar"[..]);
+        let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
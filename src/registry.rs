@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use crate::{Case, Converter};
+
+/// A fixed set of strings converted to a case once, which hands out the
+/// conversions as borrowed `&str`s.
+///
+/// This is meant for hot paths over a bounded vocabulary, such as the
+/// variants of an enum, where converting on every use would allocate. The
+/// conversions are borrowed from the registry, so when it is kept in a
+/// `static`, for example one initialized by `lazy_static`, they are
+/// `&'static str`.
+///
+/// The registry is never modified after it is built, so it can be shared
+/// between threads without locking.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{Case, CaseRegistry};
+///
+/// let registry = CaseRegistry::new(Case::Kebab, &["UserId", "CreatedAt"]);
+/// assert_eq!(registry.get("CreatedAt"), Some("created-at"));
+/// assert_eq!(registry.get("UpdatedAt"), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CaseRegistry {
+    case: Case,
+    converted: HashMap<String, Box<str>>,
+}
+
+impl CaseRegistry {
+    /// Converts each of `inputs` to `case`.
+    pub fn new<I>(case: Case, inputs: I) -> CaseRegistry
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let converter = Converter::new();
+        let mut converted = HashMap::new();
+
+        for input in inputs {
+            let input = input.as_ref();
+            if !converted.contains_key(input) {
+                let output = converter.convert(input, case).into_boxed_str();
+                converted.insert(input.to_owned(), output);
+            }
+        }
+
+        CaseRegistry { case, converted }
+    }
+
+    /// The case which the strings are converted to.
+    pub fn case(&self) -> Case {
+        self.case
+    }
+
+    /// Returns the conversion of `s`, or `None` if it was not registered.
+    ///
+    /// Every lookup of the same string borrows the same conversion.
+    pub fn get(&self, s: &str) -> Option<&str> {
+        self.converted.get(s).map(|output| &**output)
+    }
+
+    /// The number of distinct registered strings.
+    pub fn len(&self) -> usize {
+        self.converted.len()
+    }
+
+    /// Returns whether no strings are registered.
+    pub fn is_empty(&self) -> bool {
+        self.converted.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::CaseRegistry;
+    use crate::{Case, ToSnakeCase};
+
+    #[test]
+    fn matches_conversion() {
+        let names = ["UserId", "XMLHttpRequest", "ΛΌΓΟΣ"];
+        let registry = CaseRegistry::new(Case::Snake, &names);
+        for name in &names {
+            assert_eq!(registry.get(name), Some(&*name.to_snake_case()));
+        }
+        assert_eq!(registry.get("userId"), None);
+        assert_eq!(registry.case(), Case::Snake);
+    }
+
+    #[test]
+    fn same_pointer() {
+        let registry = CaseRegistry::new(Case::UpperCamel, vec!["user_id", "user_id"]);
+        let first = registry.get("user_id").unwrap();
+        let second = registry.get("user_id").unwrap();
+        assert_eq!(first, "UserId");
+        assert_eq!(first.as_ptr(), second.as_ptr());
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        let registry = Arc::new(CaseRegistry::new(Case::Kebab, &["FooBar"]));
+        let expected = registry.get("FooBar").unwrap().as_ptr() as usize;
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let registry = Arc::clone(&registry);
+                thread::spawn(move || registry.get("FooBar").unwrap().as_ptr() as usize)
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let registry = CaseRegistry::new(Case::Snake, Vec::<String>::new());
+        assert!(registry.is_empty());
+        assert_eq!(registry.get(""), None);
+    }
+}
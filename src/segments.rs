@@ -0,0 +1,153 @@
+use crate::{fix_final_sigma, BoundaryKind, Case, Words};
+
+/// The words of a string, split once so that they can be rendered in any
+/// case without splitting the string again.
+///
+/// Rendering gives the same results as the `To*Case` traits.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{Case, Segments};
+///
+/// let segments = Segments::new("XMLHttpRequest");
+/// assert_eq!(segments.len(), 3);
+/// assert_eq!(segments.to_snake_case(), "xml_http_request");
+/// assert_eq!(segments.to_case(Case::UpperCamel), "XmlHttpRequest");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Segments<'a> {
+    s: &'a str,
+    /// The words with their offsets in `s`.
+    words: Vec<(usize, &'a str, BoundaryKind)>,
+}
+
+impl<'a> Segments<'a> {
+    /// Splits `s` into words.
+    pub fn new(s: &'a str) -> Segments<'a> {
+        let mut words = Vec::new();
+        let mut prev_end = 0;
+
+        for (offset, word) in Words::new(s) {
+            let kind = BoundaryKind::from_separator(&s[prev_end..offset], words.is_empty());
+            words.push((offset, word, kind));
+            prev_end = offset + word.len();
+        }
+
+        Segments { s, words }
+    }
+
+    /// The number of words.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns whether there are no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// The words as they are written in the input, each with the boundary
+    /// which preceded it.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, BoundaryKind)> + '_ {
+        self.words.iter().map(|&(_, word, kind)| (word, kind))
+    }
+
+    /// Renders the words in `case`.
+    pub fn to_case(&self, case: Case) -> String {
+        let mut out = String::new();
+
+        for (index, &(offset, word, _)) in self.words.iter().enumerate() {
+            if index != 0 {
+                out.push_str(case.separator());
+            }
+            let start = out.len();
+            case.push_word(index, word, None, &mut out);
+            fix_final_sigma(self.s, offset, word, &mut out, start);
+        }
+
+        out
+    }
+
+    /// Renders the words in UpperCamelCase.
+    pub fn to_upper_camel_case(&self) -> String {
+        self.to_case(Case::UpperCamel)
+    }
+
+    /// Renders the words in lowerCamelCase.
+    pub fn to_lower_camel_case(&self) -> String {
+        self.to_case(Case::LowerCamel)
+    }
+
+    /// Renders the words in snake_case.
+    pub fn to_snake_case(&self) -> String {
+        self.to_case(Case::Snake)
+    }
+
+    /// Renders the words in kebab-case.
+    pub fn to_kebab_case(&self) -> String {
+        self.to_case(Case::Kebab)
+    }
+
+    /// Renders the words in SHOUTY_SNAKE_CASE.
+    pub fn to_shouty_snake_case(&self) -> String {
+        self.to_case(Case::ShoutySnake)
+    }
+
+    /// Renders the words in SHOUTY-KEBAB-CASE.
+    pub fn to_shouty_kebab_case(&self) -> String {
+        self.to_case(Case::ShoutyKebab)
+    }
+
+    /// Renders the words in Title Case.
+    pub fn to_title_case(&self) -> String {
+        self.to_case(Case::Title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Segments;
+    use crate::{
+        BoundaryKind, ToKebabCase, ToLowerCamelCase, ToShoutyKebabCase, ToShoutySnakeCase,
+        ToSnakeCase, ToTitleCase, ToUpperCamelCase,
+    };
+
+    #[test]
+    fn matches_traits() {
+        for s in &[
+            "CamelCase",
+            "This is Human case.",
+            "MixedUP CamelCase, with some Spaces",
+            "XΣXΣ baﬄe",
+            "ǆungla",
+            "O'Brien",
+            "foo\u{200c}bar\u{200b}baz",
+            "",
+        ] {
+            let segments = Segments::new(s);
+            assert_eq!(segments.to_upper_camel_case(), s.to_upper_camel_case());
+            assert_eq!(segments.to_lower_camel_case(), s.to_lower_camel_case());
+            assert_eq!(segments.to_snake_case(), s.to_snake_case());
+            assert_eq!(segments.to_kebab_case(), s.to_kebab_case());
+            assert_eq!(segments.to_shouty_snake_case(), s.to_shouty_snake_case());
+            assert_eq!(segments.to_shouty_kebab_case(), s.to_shouty_kebab_case());
+            assert_eq!(segments.to_title_case(), s.to_title_case());
+        }
+    }
+
+    #[test]
+    fn words_and_boundaries() {
+        let segments = Segments::new("fooBar baz-qux");
+        assert_eq!(
+            segments.iter().collect::<Vec<_>>(),
+            [
+                ("foo", BoundaryKind::Start),
+                ("Bar", BoundaryKind::CaseChange),
+                ("baz", BoundaryKind::Space),
+                ("qux", BoundaryKind::Hyphen),
+            ]
+        );
+        assert!(Segments::new("__").is_empty());
+    }
+}
@@ -48,4 +48,9 @@ mod tests {
     t!(test9: "XΣXΣ baﬄe" => "XΣXΣ-BAFFLE");
     t!(test10: "XMLHttpRequest" => "XML-HTTP-REQUEST");
     t!(test11: "SHOUTY-KEBAB-CASE" => "SHOUTY-KEBAB-CASE");
+    t!(test12: "A" => "A");
+    t!(test13: "a" => "A");
+    t!(test14: "5" => "5");
+    t!(test15: "_" => "");
+    t!(test16: "foo\r\nbar\tbaz" => "FOO-BAR-BAZ");
 }
@@ -1,4 +1,4 @@
-use crate::{transform, uppercase};
+use crate::{transform, uppercase, Affix, Case, Converter, Lang};
 
 /// This trait defines a shouty snake case conversion.
 ///
@@ -16,6 +16,30 @@ use crate::{transform, uppercase};
 pub trait ToShoutySnakeCase: ToOwned {
     /// Convert this type to shouty snake case.
     fn to_shouty_snake_case(&self) -> Self::Owned;
+
+    /// Convert this type to shouty snake case, with the casing rules of
+    /// `lang`.
+    ///
+    /// ```rust
+    /// use heck::{Lang, ToShoutySnakeCase};
+    ///
+    /// assert_eq!("title".to_shouty_snake_case_lang(Lang::Turkish), "TİTLE");
+    /// ```
+    fn to_shouty_snake_case_lang(&self, lang: Lang) -> Self::Owned;
+
+    /// Convert this type to an environment variable name: shouty snake case,
+    /// after `prefix` and an underscore.
+    ///
+    /// Full stops separate words, as is usual in configuration keys. The
+    /// prefix is converted to shouty snake case as well, so a trailing
+    /// underscore in it is not doubled, and an empty prefix is left out.
+    ///
+    /// ```rust
+    /// use heck::ToShoutySnakeCase;
+    ///
+    /// assert_eq!("database.url".to_env_var("APP"), "APP_DATABASE_URL");
+    /// ```
+    fn to_env_var(&self, prefix: &str) -> Self::Owned;
 }
 
 /// Oh heck, ToShoutySnekCase is an alias for ToShoutySnakeCase. See
@@ -36,6 +60,17 @@ impl ToShoutySnakeCase for str {
     fn to_shouty_snake_case(&self) -> Self::Owned {
         transform(self, uppercase, |s| s.push('_'))
     }
+
+    fn to_shouty_snake_case_lang(&self, lang: Lang) -> Self::Owned {
+        Converter::new().lang(lang).convert(self, Case::ShoutySnake)
+    }
+
+    fn to_env_var(&self, prefix: &str) -> Self::Owned {
+        let key = Converter::new()
+            .boundary_char(Some('.'))
+            .convert(self, Case::ShoutySnake);
+        Affix::Separate.join(&[&prefix.to_shouty_snake_case(), &key], "_")
+    }
 }
 
 #[cfg(test)]
@@ -61,4 +96,46 @@ mod tests {
     t!(test8: "this-contains_ ALLKinds OfWord_Boundaries" => "THIS_CONTAINS_ALL_KINDS_OF_WORD_BOUNDARIES");
     t!(test9: "XΣXΣ baﬄe" => "XΣXΣ_BAFFLE");
     t!(test10: "XMLHttpRequest" => "XML_HTTP_REQUEST");
+    t!(test11: "foo\u{200b}bar" => "FOO_BAR");
+    t!(test12: "foo\u{200c}bar" => "FOOBAR");
+    t!(test13: "A" => "A");
+    t!(test14: "a" => "A");
+    t!(test15: "5" => "5");
+    t!(test16: "_" => "");
+    t!(test17: "D3D11Device" => "D3D11_DEVICE");
+    t!(test18: "OpenGL45Context" => "OPEN_GL45_CONTEXT");
+    t!(test19: "foo\r\nbar\tbaz" => "FOO_BAR_BAZ");
+    t!(test20: "ꮳꮃꭹ ꭶꮼꮒꭿꮝꮧ" => "ᏣᎳᎩ_ᎦᏬᏂᎯᏍᏗ");
+    t!(test21: "ꮳꮃꭹᏣꮃꭹ" => "ᏣᎳᎩ_ᏣᎳᎩ");
+    t!(test22: "𐐔𐐯𐑅𐐨𐑉𐐯𐐻" => "𐐔𐐇𐐝𐐀𐐡𐐇𐐓");
+    t!(test23: "ⲀⲂⲄ ⲁⲃ" => "ⲀⲂⲄ_ⲀⲂ");
+
+    macro_rules! e {
+        ($t:ident : $s1:expr, $prefix:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_env_var($prefix), $s2)
+            }
+        };
+    }
+
+    e!(env1: "database.url", "APP" => "APP_DATABASE_URL");
+    e!(env2: "server.http.maxConnections", "APP" => "APP_SERVER_HTTP_MAX_CONNECTIONS");
+    e!(env3: "database.url", "APP_" => "APP_DATABASE_URL");
+    e!(env4: "database.url", "myApp" => "MY_APP_DATABASE_URL");
+    e!(env5: "database.url", "" => "DATABASE_URL");
+    e!(env6: "log-level", "APP" => "APP_LOG_LEVEL");
+    e!(env7: "", "APP" => "APP");
+
+    #[test]
+    fn turkish() {
+        use crate::Lang;
+
+        assert_eq!("title".to_shouty_snake_case_lang(Lang::Turkish), "TİTLE");
+        assert_eq!(
+            "ılık su".to_shouty_snake_case_lang(Lang::Turkish),
+            "ILIK_SU"
+        );
+        assert_eq!("title".to_shouty_snake_case_lang(Lang::Dutch), "TITLE");
+    }
 }
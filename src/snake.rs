@@ -1,4 +1,5 @@
-use crate::{lowercase, transform};
+use crate::charset::{check_lossless, for_each_dropped};
+use crate::{join, lowercase, lowercase_word, split_separators, transform, LossyConversion, Words};
 
 /// This trait defines a snake case conversion.
 ///
@@ -15,6 +16,157 @@ use crate::{lowercase, transform};
 pub trait ToSnakeCase: ToOwned {
     /// Convert this type to snake case.
     fn to_snake_case(&self) -> Self::Owned;
+
+    /// Convert this type to snake case, dropping the first word if it is one
+    /// of `prefixes`.
+    ///
+    /// Prefixes are compared case-insensitively against the whole first word,
+    /// so "getter" is not stripped by a "get" prefix. A word is never stripped
+    /// if it is the only word.
+    ///
+    /// ```rust
+    /// use heck::ToSnakeCase;
+    ///
+    /// assert_eq!("getUserName".to_snake_case_stripping(&["get", "set"]), "user_name");
+    /// ```
+    fn to_snake_case_stripping(&self, prefixes: &[&str]) -> Self::Owned;
+
+    /// Convert this type to snake case, keeping the first `count` segments as
+    /// they are.
+    ///
+    /// Segments are separated by whitespace, punctuation and underscores, but
+    /// not by changes of case, so a prefix such as "MyOrg" is kept whole.
+    ///
+    /// ```rust
+    /// use heck::ToSnakeCase;
+    ///
+    /// assert_eq!("MyOrg UserService".to_snake_case_skipping_prefix(1), "MyOrg_user_service");
+    /// assert_eq!("myorg::UserService".to_snake_case_skipping_prefix(1), "myorg_user_service");
+    /// ```
+    fn to_snake_case_skipping_prefix(&self, count: usize) -> Self::Owned;
+
+    /// Convert the name of a Java getter or setter to the snake case name of
+    /// its property.
+    ///
+    /// This strips a leading "get", "set" or "is" word like
+    /// `to_snake_case_stripping`, so acronyms are split from the following
+    /// word as in any other conversion.
+    ///
+    /// ```rust
+    /// use heck::ToSnakeCase;
+    ///
+    /// assert_eq!("getHTTPResponseCode".to_java_property_snake_case(), "http_response_code");
+    /// assert_eq!("isEmpty".to_java_property_snake_case(), "empty");
+    /// ```
+    fn to_java_property_snake_case(&self) -> Self::Owned;
+
+    /// Convert this type to snake case, dropping trailing words so that the
+    /// result is at most `max_bytes` long.
+    ///
+    /// Words are never cut, so if the first word does not fit on its own the
+    /// result is empty.
+    ///
+    /// ```rust
+    /// use heck::ToSnakeCase;
+    ///
+    /// let sentence = "We carry a new world here, in our hearts.";
+    /// assert_eq!(sentence.to_snake_case_truncated(20), "we_carry_a_new_world");
+    /// ```
+    fn to_snake_case_truncated(&self, max_bytes: usize) -> Self::Owned;
+
+    /// Convert this type to snake case with at most `max_words` words, fusing
+    /// any words beyond the limit onto the last one.
+    ///
+    /// A limit of zero gives an empty string.
+    ///
+    /// ```rust
+    /// use heck::ToSnakeCase;
+    ///
+    /// assert_eq!("a b c d".to_snake_case_max_words(3), "a_b_cd");
+    /// ```
+    fn to_snake_case_max_words(&self, max_words: usize) -> Self::Owned;
+
+    /// Convert this type to snake case, as UTF-8 encoded bytes.
+    ///
+    /// The bytes are those of the converted string, which is handed over
+    /// without being copied.
+    fn to_snake_case_bytes_vec(&self) -> Vec<u8>;
+
+    /// Convert this type to snake case, failing if any characters other than
+    /// whitespace, underscores and hyphens are dropped.
+    ///
+    /// ```rust
+    /// use heck::ToSnakeCase;
+    ///
+    /// assert_eq!("fooBar".try_to_snake_case().unwrap(), "foo_bar");
+    /// assert_eq!("foo🎉bar".try_to_snake_case().unwrap_err().dropped, vec!['🎉']);
+    /// ```
+    fn try_to_snake_case(&self) -> Result<Self::Owned, LossyConversion>;
+
+    /// Convert this type to snake case, calling `observe` with each character
+    /// which is dropped, other than whitespace, underscores and hyphens.
+    ///
+    /// The characters are observed in the order they appear in the input,
+    /// which helps to find out why symbols such as emoji are missing from
+    /// the result.
+    ///
+    /// ```rust
+    /// use heck::ToSnakeCase;
+    ///
+    /// let mut dropped = Vec::new();
+    /// assert_eq!("foo🎉bar!".to_snake_case_observing(|c| dropped.push(c)), "foo_bar");
+    /// assert_eq!(dropped, vec!['🎉', '!']);
+    /// ```
+    fn to_snake_case_observing<F>(&self, observe: F) -> Self::Owned
+    where
+        F: FnMut(char);
+
+    /// Convert this type to snake case, with a fixed prefix and suffix.
+    ///
+    /// The prefix and suffix are copied as they are, and `affix` decides
+    /// whether they are joined to the converted string with underscores.
+    /// Empty affixes are left out.
+    ///
+    /// ```rust
+    /// use heck::{Affix, ToSnakeCase};
+    ///
+    /// assert_eq!("userId".to_snake_case_affixed("m", "", Affix::Separate), "m_user_id");
+    /// assert_eq!("userId".to_snake_case_affixed("", "_t", Affix::Fuse), "user_id_t");
+    /// ```
+    fn to_snake_case_affixed(&self, prefix: &str, suffix: &str, affix: Affix) -> Self::Owned;
+}
+
+/// The prefixes of Java getter and setter names.
+const JAVA_ACCESSOR_PREFIXES: &[&str] = &["get", "set", "is"];
+
+/// How a prefix or suffix is joined to a converted string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Affix {
+    /// The affix is joined directly to the converted string.
+    Fuse,
+    /// The affix is joined to the converted string with the separator of the
+    /// case.
+    Separate,
+}
+
+impl Affix {
+    /// Joins the non-empty parts with `separator` if the affixes are
+    /// separate, or directly otherwise.
+    pub(crate) fn join(self, parts: &[&str], separator: &str) -> String {
+        let separator = match self {
+            Affix::Fuse => "",
+            Affix::Separate => separator,
+        };
+
+        let mut out = String::new();
+        for part in parts.iter().filter(|part| !part.is_empty()) {
+            if !out.is_empty() {
+                out.push_str(separator);
+            }
+            out.push_str(part);
+        }
+        out
+    }
 }
 
 /// Oh heck, SnekCase is an alias for ToSnakeCase. See ToSnakeCase for
@@ -34,11 +186,105 @@ impl ToSnakeCase for str {
     fn to_snake_case(&self) -> String {
         transform(self, lowercase, |s| s.push('_'))
     }
+
+    fn to_snake_case_stripping(&self, prefixes: &[&str]) -> String {
+        let mut words: Vec<(usize, &str)> = Words::new(self).collect();
+
+        if words.len() > 1 {
+            let first = words[0].1.to_lowercase();
+            if prefixes.iter().any(|prefix| prefix.to_lowercase() == first) {
+                words.remove(0);
+            }
+        }
+
+        join(self, words, lowercase, |s| s.push('_'))
+    }
+
+    fn to_snake_case_skipping_prefix(&self, count: usize) -> String {
+        let mut out = String::new();
+
+        for (index, segment) in split_separators(self).enumerate() {
+            if index < count {
+                if !out.is_empty() {
+                    out.push('_');
+                }
+                out.push_str(segment);
+                continue;
+            }
+            for (offset, word) in Words::new(segment) {
+                if !out.is_empty() {
+                    out.push('_');
+                }
+                lowercase_word(segment, offset, word, &mut out);
+            }
+        }
+
+        out
+    }
+
+    fn to_java_property_snake_case(&self) -> String {
+        self.to_snake_case_stripping(JAVA_ACCESSOR_PREFIXES)
+    }
+
+    fn to_snake_case_truncated(&self, max_bytes: usize) -> String {
+        let mut out = String::new();
+
+        for (offset, word) in Words::new(self) {
+            let word_start = out.len();
+            if word_start != 0 {
+                out.push('_');
+            }
+            lowercase_word(self, offset, word, &mut out);
+
+            if out.len() > max_bytes {
+                out.truncate(word_start);
+                break;
+            }
+        }
+
+        out
+    }
+
+    fn to_snake_case_max_words(&self, max_words: usize) -> String {
+        let mut out = String::new();
+        if max_words == 0 {
+            return out;
+        }
+
+        for (index, (offset, word)) in Words::new(self).enumerate() {
+            if index != 0 && index < max_words {
+                out.push('_');
+            }
+            lowercase_word(self, offset, word, &mut out);
+        }
+
+        out
+    }
+
+    fn to_snake_case_bytes_vec(&self) -> Vec<u8> {
+        self.to_snake_case().into_bytes()
+    }
+
+    fn try_to_snake_case(&self) -> Result<String, LossyConversion> {
+        check_lossless(self, self.to_snake_case())
+    }
+
+    fn to_snake_case_observing<F>(&self, observe: F) -> String
+    where
+        F: FnMut(char),
+    {
+        for_each_dropped(self, observe);
+        self.to_snake_case()
+    }
+
+    fn to_snake_case_affixed(&self, prefix: &str, suffix: &str, affix: Affix) -> String {
+        affix.join(&[prefix, &self.to_snake_case(), suffix], "_")
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ToSnakeCase;
+    use super::{Affix, ToSnakeCase};
 
     macro_rules! t {
         ($t:ident : $s1:expr => $s2:expr) => {
@@ -74,4 +320,199 @@ mod tests {
     t!(test23: "ABC123dEEf456FOO" => "abc123d_e_ef456_foo");
     t!(test24: "abcDEF" => "abc_def");
     t!(test25: "ABcDE" => "a_bc_de");
+    t!(test26: "foo\u{200b}bar" => "foo_bar");
+    t!(test27: "foo\u{200c}bar" => "foobar");
+    t!(test28: "Foo\u{200b}Bar\u{200c}Baz" => "foo_bar_baz");
+    t!(test29: "A" => "a");
+    t!(test30: "a" => "a");
+    t!(test31: "5" => "5");
+    t!(test32: "_" => "");
+    t!(test33: "parseXML" => "parse_xml");
+    t!(test34: "parseXMLData" => "parse_xml_data");
+    t!(test35: "aHTTPServer" => "a_http_server");
+    t!(test36: "theURLParser" => "the_url_parser");
+    t!(test37: "\u{FEFF}fooBar" => "foo_bar");
+    t!(test38: "soft\u{00AD}ware" => "software");
+    t!(test39: "foo\u{00AD}Bar" => "foo_bar");
+    t!(test40: "\u{200F}foo\u{200E}_bar\u{200E}" => "foo_bar");
+    t!(test41: "FOO\u{FEFF}Bar" => "foo_bar");
+    t!(test42: "D3D11Device" => "d3d11_device");
+    t!(test43: "OpenGL45Context" => "open_gl45_context");
+    t!(test44: "ID3D12GraphicsCommandList" => "id3d12_graphics_command_list");
+    t!(test45: "DX12Renderer" => "dx12_renderer");
+    t!(test46: "H264Decoder" => "h264_decoder");
+    t!(test47: "foo\r\nbar\tbaz" => "foo_bar_baz");
+    t!(test48: "\r\n\tfoo \t\r\n Bar\r" => "foo_bar");
+    t!(test49: "O'Brien" => "o'brien");
+    t!(test50: "O\u{2019}Brien" => "o\u{2019}brien");
+    t!(test51: "ᏣᎳᎩ ᎦᏬᏂᎯᏍᏗ" => "ꮳꮃꭹ_ꭶꮼꮒꭿꮝꮧ");
+    t!(test52: "ꮳꮃꭹᏣꮃꭹ" => "ꮳꮃꭹ_ꮳꮃꭹ");
+    t!(test53: "𐐔𐐯𐑅𐐨𐑉𐐯𐐻𐐔𐐯𐑅𐐨𐑉𐐯𐐻" => "𐐼𐐯𐑅𐐨𐑉𐐯𐐻_𐐼𐐯𐑅𐐨𐑉𐐯𐐻");
+    t!(test54: "ⲁⲃⲀⲃ" => "ⲁⲃ_ⲁⲃ");
+    t!(test55: "ΛΌΓΟΣ" => "λόγος");
+    t!(test56: "λόγοΣ" => "λόγο_ς");
+    t!(test57: "Σ" => "σ");
+    t!(test58: "Σ_Α" => "σ_α");
+    t!(test59: "ΑΣ1Β" => "ας1β");
+    t!(test60: "ΟΔΟΣΚαι" => "οδοσ_και");
+
+    macro_rules! s {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_snake_case_stripping(&["get", "set", "is"]), $s2)
+            }
+        };
+    }
+
+    s!(stripping1: "getUserName" => "user_name");
+    s!(stripping2: "isReady" => "ready");
+    s!(stripping3: "getter" => "getter");
+    s!(stripping4: "SET_VALUE" => "value");
+    s!(stripping5: "get" => "get");
+    s!(stripping6: "userName" => "user_name");
+
+    macro_rules! sp {
+        ($t:ident : $s1:expr, $n:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_snake_case_skipping_prefix($n), $s2)
+            }
+        };
+    }
+
+    sp!(skipping_prefix1: "MyOrg UserService", 1 => "MyOrg_user_service");
+    sp!(skipping_prefix2: "myorg::UserService", 1 => "myorg_user_service");
+    sp!(skipping_prefix3: "Acme::Core::HttpClient", 2 => "Acme_Core_http_client");
+    sp!(skipping_prefix4: "MyOrg UserService", 0 => "my_org_user_service");
+    sp!(skipping_prefix5: "MyOrg UserService", 5 => "MyOrg_UserService");
+    sp!(skipping_prefix6: "__MyOrg__userName", 1 => "MyOrg_user_name");
+    sp!(skipping_prefix7: "", 1 => "");
+
+    macro_rules! j {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_java_property_snake_case(), $s2)
+            }
+        };
+    }
+
+    j!(java1: "getHTTPResponseCode" => "http_response_code");
+    j!(java2: "isEmpty" => "empty");
+    j!(java3: "setURLValue" => "url_value");
+    j!(java4: "getX" => "x");
+    j!(java5: "getter" => "getter");
+    j!(java6: "isolate" => "isolate");
+    j!(java7: "get" => "get");
+    j!(java8: "toString" => "to_string");
+
+    macro_rules! tr {
+        ($t:ident : $s1:expr, $n:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_snake_case_truncated($n), $s2)
+            }
+        };
+    }
+
+    tr!(truncated1: "We carry a new world here, in our hearts.", 20 => "we_carry_a_new_world");
+    tr!(truncated2: "We carry a new world here, in our hearts.", 19 => "we_carry_a_new");
+    tr!(truncated3: "CamelCase", 100 => "camel_case");
+    tr!(truncated4: "CamelCase", 7 => "camel");
+    tr!(truncated5: "Supercalifragilistic", 5 => "");
+    tr!(truncated6: "ΣΣΣ", 3 => "");
+    tr!(truncated7: "CamelCase", 0 => "");
+    tr!(truncated8: "CamelCase", 4 => "");
+    tr!(truncated9: "CamelCase", 5 => "camel");
+
+    macro_rules! mw {
+        ($t:ident : $s1:expr, $n:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_snake_case_max_words($n), $s2)
+            }
+        };
+    }
+
+    mw!(max_words1: "a b c d", 3 => "a_b_cd");
+    mw!(max_words2: "XMLHttpRequestBody", 2 => "xml_httprequestbody");
+    mw!(max_words3: "a b c d", 4 => "a_b_c_d");
+    mw!(max_words4: "a b c d", 10 => "a_b_c_d");
+    mw!(max_words5: "a b c d", 1 => "abcd");
+    mw!(max_words6: "a b c d", 0 => "");
+    mw!(max_words7: "__foo--bar__baz__", 2 => "foo_barbaz");
+    mw!(max_words8: "", 3 => "");
+
+    macro_rules! af {
+        ($t:ident : $s1:expr, $prefix:expr, $suffix:expr, $affix:ident => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(
+                    $s1.to_snake_case_affixed($prefix, $suffix, Affix::$affix),
+                    $s2
+                )
+            }
+        };
+    }
+
+    af!(affixed1: "userId", "m", "", Separate => "m_user_id");
+    af!(affixed2: "userId", "m", "", Fuse => "muser_id");
+    af!(affixed3: "FooBar", "", "t", Separate => "foo_bar_t");
+    af!(affixed4: "FooBar", "", "_t", Fuse => "foo_bar_t");
+    af!(affixed5: "FooBar", "Pre", "Post", Separate => "Pre_foo_bar_Post");
+    af!(affixed6: "", "m", "t", Separate => "m_t");
+
+    #[test]
+    fn try_lossless() {
+        for s in &[
+            "foo_bar",
+            "fooBar",
+            "foo-bar baz",
+            "  __x__  ",
+            "",
+            "\u{FEFF}soft\u{00AD}ware",
+        ] {
+            assert_eq!(s.try_to_snake_case(), Ok(s.to_snake_case()));
+        }
+    }
+
+    #[test]
+    fn try_lossy() {
+        let err = "foo🎉bar, baz!".try_to_snake_case().unwrap_err();
+        assert_eq!(err.dropped, vec!['🎉', ',', '!']);
+        assert_eq!(err.converted, "foo_bar_baz");
+    }
+
+    #[test]
+    fn observing() {
+        let mut dropped = Vec::new();
+        assert_eq!("a🎉b".to_snake_case_observing(|c| dropped.push(c)), "a_b");
+        assert_eq!(dropped, vec!['🎉']);
+
+        let mut count = 0;
+        assert_eq!(
+            "foo_bar-baz qux".to_snake_case_observing(|_| count += 1),
+            "foo_bar_baz_qux"
+        );
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn smart_pointers() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let arc: Arc<str> = Arc::from("FooBar");
+        let rc: Rc<str> = Rc::from("FooBar");
+        assert_eq!(arc.to_snake_case(), "foo_bar");
+        assert_eq!(rc.to_snake_case(), "foo_bar");
+    }
+
+    #[test]
+    fn bytes_vec() {
+        for s in &["CamelCase", "XΣXΣ baﬄe", ""] {
+            assert_eq!(s.to_snake_case_bytes_vec(), s.to_snake_case().as_bytes());
+        }
+    }
 }
@@ -0,0 +1,491 @@
+use crate::ToSnakeCase;
+
+/// The SQL dialects known to `to_sql_ident`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SqlDialect {
+    /// PostgreSQL, which quotes identifiers with double quotes.
+    Postgres,
+    /// MySQL, which quotes identifiers with backticks.
+    MySql,
+    /// Another dialect, given by its reserved words and the character which
+    /// quotes identifiers. The reserved words are matched ignoring ASCII
+    /// case.
+    Custom {
+        /// The reserved words of the dialect.
+        keywords: &'static [&'static str],
+        /// The character placed on both sides of a quoted identifier.
+        quote: char,
+    },
+}
+
+impl SqlDialect {
+    /// Returns whether `ident`, which is in snake case, is a reserved word of
+    /// this dialect.
+    fn is_keyword(self, ident: &str) -> bool {
+        match self {
+            SqlDialect::Postgres => POSTGRES_KEYWORDS.binary_search(&ident).is_ok(),
+            SqlDialect::MySql => MYSQL_KEYWORDS.binary_search(&ident).is_ok(),
+            SqlDialect::Custom { keywords, .. } => keywords
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(ident)),
+        }
+    }
+
+    /// The character which quotes identifiers in this dialect.
+    fn quote(self) -> char {
+        match self {
+            SqlDialect::Postgres => '"',
+            SqlDialect::MySql => '`',
+            SqlDialect::Custom { quote, .. } => quote,
+        }
+    }
+}
+
+/// Converts a string to a SQL identifier, in snake_case, quoting it if it is
+/// a reserved word of `dialect` or starts with a digit, which unquoted
+/// identifiers can't.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{to_sql_ident, SqlDialect};
+///
+/// assert_eq!(to_sql_ident("userName", SqlDialect::Postgres), "user_name");
+/// assert_eq!(to_sql_ident("Order", SqlDialect::Postgres), "\"order\"");
+/// assert_eq!(to_sql_ident("Order", SqlDialect::MySql), "`order`");
+/// assert_eq!(to_sql_ident("1st place", SqlDialect::Postgres), "\"1st_place\"");
+///
+/// let sqlite = SqlDialect::Custom {
+///     keywords: &["ABORT", "ORDER"],
+///     quote: '"',
+/// };
+/// assert_eq!(to_sql_ident("abort", sqlite), "\"abort\"");
+/// ```
+pub fn to_sql_ident(s: &str, dialect: SqlDialect) -> String {
+    let ident = s.to_snake_case();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) || dialect.is_keyword(&ident) {
+        let quote = dialect.quote();
+        format!("{}{}{}", quote, ident, quote)
+    } else {
+        ident
+    }
+}
+
+/// The reserved key words of PostgreSQL 16, including those which may be used
+/// as function or type names.
+const POSTGRES_KEYWORDS: &[&str] = &[
+    "all",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "authorization",
+    "binary",
+    "both",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "collation",
+    "column",
+    "concurrently",
+    "constraint",
+    "create",
+    "cross",
+    "current_catalog",
+    "current_date",
+    "current_role",
+    "current_schema",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "deferrable",
+    "desc",
+    "distinct",
+    "do",
+    "else",
+    "end",
+    "except",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "freeze",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "ilike",
+    "in",
+    "initially",
+    "inner",
+    "intersect",
+    "into",
+    "is",
+    "isnull",
+    "join",
+    "lateral",
+    "leading",
+    "left",
+    "like",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "natural",
+    "not",
+    "notnull",
+    "null",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "outer",
+    "overlaps",
+    "placing",
+    "primary",
+    "references",
+    "returning",
+    "right",
+    "select",
+    "session_user",
+    "similar",
+    "some",
+    "symmetric",
+    "system_user",
+    "table",
+    "tablesample",
+    "then",
+    "to",
+    "trailing",
+    "true",
+    "union",
+    "unique",
+    "user",
+    "using",
+    "variadic",
+    "verbose",
+    "when",
+    "where",
+    "window",
+    "with",
+];
+
+/// The reserved words of MySQL 8.0.
+const MYSQL_KEYWORDS: &[&str] = &[
+    "accessible",
+    "add",
+    "all",
+    "alter",
+    "analyze",
+    "and",
+    "as",
+    "asc",
+    "asensitive",
+    "before",
+    "between",
+    "bigint",
+    "binary",
+    "blob",
+    "both",
+    "by",
+    "call",
+    "cascade",
+    "case",
+    "change",
+    "char",
+    "character",
+    "check",
+    "collate",
+    "column",
+    "condition",
+    "constraint",
+    "continue",
+    "convert",
+    "create",
+    "cross",
+    "cube",
+    "cume_dist",
+    "current_date",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "cursor",
+    "database",
+    "databases",
+    "day_hour",
+    "day_microsecond",
+    "day_minute",
+    "day_second",
+    "dec",
+    "decimal",
+    "declare",
+    "default",
+    "delayed",
+    "delete",
+    "dense_rank",
+    "desc",
+    "describe",
+    "deterministic",
+    "distinct",
+    "distinctrow",
+    "div",
+    "double",
+    "drop",
+    "dual",
+    "each",
+    "else",
+    "elseif",
+    "empty",
+    "enclosed",
+    "escaped",
+    "except",
+    "exists",
+    "exit",
+    "explain",
+    "false",
+    "fetch",
+    "first_value",
+    "float",
+    "float4",
+    "float8",
+    "for",
+    "force",
+    "foreign",
+    "from",
+    "fulltext",
+    "function",
+    "generated",
+    "get",
+    "grant",
+    "group",
+    "grouping",
+    "groups",
+    "having",
+    "high_priority",
+    "hour_microsecond",
+    "hour_minute",
+    "hour_second",
+    "if",
+    "ignore",
+    "in",
+    "index",
+    "infile",
+    "inner",
+    "inout",
+    "insensitive",
+    "insert",
+    "int",
+    "int1",
+    "int2",
+    "int3",
+    "int4",
+    "int8",
+    "integer",
+    "intersect",
+    "interval",
+    "into",
+    "io_after_gtids",
+    "io_before_gtids",
+    "is",
+    "iterate",
+    "join",
+    "json_table",
+    "key",
+    "keys",
+    "kill",
+    "lag",
+    "last_value",
+    "lateral",
+    "lead",
+    "leading",
+    "leave",
+    "left",
+    "like",
+    "limit",
+    "linear",
+    "lines",
+    "load",
+    "localtime",
+    "localtimestamp",
+    "lock",
+    "long",
+    "longblob",
+    "longtext",
+    "loop",
+    "low_priority",
+    "master_bind",
+    "master_ssl_verify_server_cert",
+    "match",
+    "maxvalue",
+    "mediumblob",
+    "mediumint",
+    "mediumtext",
+    "middleint",
+    "minute_microsecond",
+    "minute_second",
+    "mod",
+    "modifies",
+    "natural",
+    "no_write_to_binlog",
+    "not",
+    "nth_value",
+    "ntile",
+    "null",
+    "numeric",
+    "of",
+    "on",
+    "optimize",
+    "optimizer_costs",
+    "option",
+    "optionally",
+    "or",
+    "order",
+    "out",
+    "outer",
+    "outfile",
+    "over",
+    "partition",
+    "percent_rank",
+    "precision",
+    "primary",
+    "procedure",
+    "purge",
+    "range",
+    "rank",
+    "read",
+    "read_write",
+    "reads",
+    "real",
+    "recursive",
+    "references",
+    "regexp",
+    "release",
+    "rename",
+    "repeat",
+    "replace",
+    "require",
+    "resignal",
+    "restrict",
+    "return",
+    "revoke",
+    "right",
+    "rlike",
+    "row",
+    "row_number",
+    "rows",
+    "schema",
+    "schemas",
+    "second_microsecond",
+    "select",
+    "sensitive",
+    "separator",
+    "set",
+    "show",
+    "signal",
+    "smallint",
+    "spatial",
+    "specific",
+    "sql",
+    "sql_big_result",
+    "sql_calc_found_rows",
+    "sql_small_result",
+    "sqlexception",
+    "sqlstate",
+    "sqlwarning",
+    "ssl",
+    "starting",
+    "stored",
+    "straight_join",
+    "system",
+    "table",
+    "terminated",
+    "then",
+    "tinyblob",
+    "tinyint",
+    "tinytext",
+    "to",
+    "trailing",
+    "trigger",
+    "true",
+    "undo",
+    "union",
+    "unique",
+    "unlock",
+    "unsigned",
+    "update",
+    "usage",
+    "use",
+    "using",
+    "utc_date",
+    "utc_time",
+    "utc_timestamp",
+    "values",
+    "varbinary",
+    "varchar",
+    "varcharacter",
+    "varying",
+    "virtual",
+    "when",
+    "where",
+    "while",
+    "window",
+    "with",
+    "write",
+    "xor",
+    "year_month",
+    "zerofill",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{to_sql_ident, SqlDialect, MYSQL_KEYWORDS, POSTGRES_KEYWORDS};
+
+    macro_rules! t {
+        ($t:ident : $s1:expr, $dialect:ident => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(to_sql_ident($s1, SqlDialect::$dialect), $s2)
+            }
+        };
+    }
+
+    t!(test1: "order", Postgres => "\"order\"");
+    t!(test2: "user_name", Postgres => "user_name");
+    t!(test3: "User", Postgres => "\"user\"");
+    t!(test4: "User", MySql => "user");
+    t!(test5: "ORDER", MySql => "`order`");
+    t!(test6: "Group", Postgres => "\"group\"");
+    t!(test7: "key", MySql => "`key`");
+    t!(test8: "key", Postgres => "key");
+    t!(test9: "orderBy", Postgres => "order_by");
+    t!(test10: "current user", Postgres => "\"current_user\"");
+    t!(test11: "1st place", Postgres => "\"1st_place\"");
+    t!(test12: "2fa", MySql => "`2fa`");
+    t!(test13: "place1", Postgres => "place1");
+
+    #[test]
+    fn custom_dialect() {
+        let dialect = SqlDialect::Custom {
+            keywords: &["TOP", "identity"],
+            quote: '"',
+        };
+        assert_eq!(to_sql_ident("top", dialect), "\"top\"");
+        assert_eq!(to_sql_ident("Identity", dialect), "\"identity\"");
+        assert_eq!(to_sql_ident("order", dialect), "order");
+        assert_eq!(to_sql_ident("3d", dialect), "\"3d\"");
+    }
+
+    #[test]
+    fn keywords_are_sorted() {
+        for keywords in &[POSTGRES_KEYWORDS, MYSQL_KEYWORDS] {
+            assert!(keywords.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::charset::dropped_chars;
+use crate::Words;
+
+/// Statistics about how a string is split into words, returned by
+/// `conversion_stats`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// The number of words, as counted by `word_count`.
+    pub words: usize,
+    /// Whether the string contains underscores.
+    pub underscores: bool,
+    /// Whether the string contains hyphens.
+    pub hyphens: bool,
+    /// Whether any word boundary was detected from a change of case, with no
+    /// separator between the words.
+    pub case_changes: bool,
+    /// The number of characters which the case conversions drop, other than
+    /// whitespace, underscores and hyphens.
+    pub dropped: usize,
+}
+
+/// Analyzes how the case conversions split a string into words, without
+/// converting it.
+///
+/// This is useful for scoring the readability of identifiers.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::conversion_stats;
+///
+/// let stats = conversion_stats("XMLHttpRequest");
+/// assert_eq!(stats.words, 3);
+/// assert!(stats.case_changes);
+/// assert!(!stats.underscores);
+/// ```
+pub fn conversion_stats(s: &str) -> Stats {
+    let mut stats = Stats {
+        underscores: s.contains('_'),
+        hyphens: s.contains('-'),
+        dropped: dropped_chars(s).len(),
+        ..Stats::default()
+    };
+
+    let mut prev_end = None;
+    for (offset, word) in Words::new(s) {
+        if prev_end == Some(offset) {
+            stats.case_changes = true;
+        }
+        stats.words += 1;
+        prev_end = Some(offset + word.len());
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{conversion_stats, Stats};
+
+    #[test]
+    fn camel_case() {
+        assert_eq!(
+            conversion_stats("XMLHttpRequest"),
+            Stats {
+                words: 3,
+                underscores: false,
+                hyphens: false,
+                case_changes: true,
+                dropped: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn doubled_underscore() {
+        assert_eq!(
+            conversion_stats("foo__bar"),
+            Stats {
+                words: 2,
+                underscores: true,
+                hyphens: false,
+                case_changes: false,
+                dropped: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn mixed_separators() {
+        assert_eq!(
+            conversion_stats("foo-barBaz, qux!"),
+            Stats {
+                words: 4,
+                underscores: false,
+                hyphens: true,
+                case_changes: true,
+                dropped: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(conversion_stats(""), Stats::default());
+    }
+}
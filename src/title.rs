@@ -1,4 +1,4 @@
-use crate::{capitalize, transform};
+use crate::{capitalize, split_separators, transform, Words};
 
 /// This trait defines a title case conversion.
 ///
@@ -16,12 +16,57 @@ use crate::{capitalize, transform};
 pub trait ToTitleCase: ToOwned {
     /// Convert this type to title case.
     fn to_title_case(&self) -> Self::Owned;
+
+    /// Convert this type to title case, leaving words with an internal
+    /// capital as they are.
+    ///
+    /// A word has an internal capital if it contains an uppercase letter
+    /// right after a lowercase one, as brand names such as "iPhone" and
+    /// "YouTube" do. Such a word is only delimited by whitespace, punctuation
+    /// and underscores, so it is not split at its changes of case.
+    ///
+    /// ```rust
+    /// use heck::ToTitleCase;
+    ///
+    /// assert_eq!("the iPhone 15 pro".to_title_case_keep_capitals(), "The iPhone 15 Pro");
+    /// ```
+    fn to_title_case_keep_capitals(&self) -> Self::Owned;
 }
 
 impl ToTitleCase for str {
     fn to_title_case(&self) -> String {
         transform(self, capitalize, |s| s.push(' '))
     }
+
+    fn to_title_case_keep_capitals(&self) -> String {
+        let mut out = String::new();
+
+        for segment in split_separators(self) {
+            if has_internal_capital(segment) {
+                if !out.is_empty() {
+                    out.push(' ');
+                }
+                out.push_str(segment);
+                continue;
+            }
+            for (_, word) in Words::new(segment) {
+                if !out.is_empty() {
+                    out.push(' ');
+                }
+                capitalize(word, &mut out);
+            }
+        }
+
+        out
+    }
+}
+
+/// Returns whether `s` contains an uppercase letter right after a lowercase
+/// letter.
+fn has_internal_capital(s: &str) -> bool {
+    s.chars()
+        .zip(s.chars().skip(1))
+        .any(|(c, next)| c.is_lowercase() && next.is_uppercase())
 }
 
 #[cfg(test)]
@@ -47,4 +92,42 @@ mod tests {
     t!(test8: "this-contains_ ALLKinds OfWord_Boundaries" => "This Contains All Kinds Of Word Boundaries");
     t!(test9: "XΣXΣ baﬄe" => "Xσxς Baﬄe");
     t!(test10: "XMLHttpRequest" => "Xml Http Request");
+    t!(test11: "ǆungla" => "ǅungla");
+    t!(test12: "ǉubav ǌegoš" => "ǈubav ǋegoš");
+    t!(test13: "ǄUNGLA" => "ǅungla");
+    t!(test14: "ǳemal" => "ǲemal");
+    t!(test15: "ﬁle ßtraße" => "File Sstraße");
+    t!(test16: "foo\u{200b}bar" => "Foo Bar");
+    t!(test17: "foo\u{200c}bar" => "Foobar");
+    t!(test18: "A" => "A");
+    t!(test19: "a" => "A");
+    t!(test20: "5" => "5");
+    t!(test21: "_" => "");
+    t!(test22: "foo\r\nbar\tbaz" => "Foo Bar Baz");
+    t!(test23: "O'Brien" => "O'Brien");
+    t!(test24: "O\u{2019}Brien" => "O\u{2019}Brien");
+    t!(test25: "o'brien" => "O'brien");
+    t!(test26: "DON'T STOP" => "Don't Stop");
+    t!(test27: "ΛΌΓΟΣ ΣΑΣ" => "Λόγος Σας");
+    t!(test28: "ΟΔΟΣ_ΚΑΙ" => "Οδος Και");
+    t!(test29: "foo🎉bar🎊baz" => "Foo Bar Baz");
+
+    macro_rules! k {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_title_case_keep_capitals(), $s2)
+            }
+        };
+    }
+
+    k!(keep_capitals1: "the iPhone 15 pro" => "The iPhone 15 Pro");
+    k!(keep_capitals2: "iPhone" => "iPhone");
+    k!(keep_capitals3: "iphone" => "Iphone");
+    k!(keep_capitals4: "watch YouTube on my_iPad" => "Watch YouTube On My iPad");
+    k!(keep_capitals5: "SHOUTY_SNAKE_CASE" => "Shouty Snake Case");
+    k!(keep_capitals6: "XMLHTTP request" => "Xmlhttp Request");
+    k!(keep_capitals7: "O'Brien and eBay's" => "O'Brien And eBay's");
+    k!(keep_capitals8: "kebab-case, McDonald" => "Kebab Case McDonald");
+    k!(keep_capitals9: "" => "");
 }
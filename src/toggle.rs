@@ -0,0 +1,66 @@
+use crate::is_final_sigma;
+
+/// This trait defines a toggle case conversion.
+///
+/// In tOGGLE cASE, the case of every letter is flipped. The string is not
+/// split into words, so all other characters are kept as they are.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::ToToggleCase;
+///
+/// let sentence = "We are going to inherit the earth.";
+/// assert_eq!(sentence.to_toggle_case(), "wE ARE GOING TO INHERIT THE EARTH.");
+/// ```
+pub trait ToToggleCase: ToOwned {
+    /// Convert this type to toggle case.
+    fn to_toggle_case(&self) -> Self::Owned;
+}
+
+impl ToToggleCase for str {
+    fn to_toggle_case(&self) -> String {
+        let mut out = String::with_capacity(self.len());
+
+        for (i, c) in self.char_indices() {
+            if c.is_uppercase() {
+                if c == 'Σ' && is_final_sigma(self, i) {
+                    out.push('ς');
+                } else {
+                    out.extend(c.to_lowercase());
+                }
+            } else if c.is_lowercase() {
+                out.extend(c.to_uppercase());
+            } else {
+                out.push(c);
+            }
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToToggleCase;
+
+    macro_rules! t {
+        ($t:ident : $s1:expr => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!($s1.to_toggle_case(), $s2)
+            }
+        };
+    }
+
+    t!(test1: "Hello World" => "hELLO wORLD");
+    t!(test2: "hELLO wORLD" => "Hello World");
+    t!(test3: "snake_case-123" => "SNAKE_CASE-123");
+    t!(test4: "ΛΌΓΟΣ ΣΑΣ" => "λόγος σας");
+    t!(test5: "ß" => "SS");
+    t!(test6: "" => "");
+    t!(test7: "Σ" => "σ");
+    t!(test8: "1Σ" => "1σ");
+    t!(test9: "ΟΣ1" => "ος1");
+    t!(test10: "ΟΣa" => "οσA");
+}
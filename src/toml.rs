@@ -0,0 +1,66 @@
+use crate::{Case, Converter};
+
+/// Converts a string to a TOML key in `case`, quoting it if it is not a valid
+/// bare key.
+///
+/// A bare key is made of ASCII letters, ASCII digits, underscores and
+/// hyphens, so a key with non-ASCII letters, or in Title Case, is written as
+/// a quoted key instead.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{to_toml_key, Case};
+///
+/// assert_eq!(to_toml_key("maxConnections", Case::Kebab), "max-connections");
+/// assert_eq!(to_toml_key("Crème Brûlée", Case::Snake), "\"crème_brûlée\"");
+/// ```
+pub fn to_toml_key(s: &str, case: Case) -> String {
+    let key = Converter::new().convert(s, case);
+    if is_bare_key(&key) {
+        return key;
+    }
+
+    let mut quoted = String::with_capacity(key.len() + 2);
+    quoted.push('"');
+    for c in key.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Returns whether `key` can be written as a TOML bare key.
+fn is_bare_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_toml_key;
+    use crate::Case;
+
+    macro_rules! t {
+        ($t:ident : $s1:expr, $case:ident => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(to_toml_key($s1, Case::$case), $s2)
+            }
+        };
+    }
+
+    t!(test1: "maxConnections", Kebab => "max-connections");
+    t!(test2: "maxConnections", Snake => "max_connections");
+    t!(test3: "HTTP2Server", ShoutySnake => "HTTP2_SERVER");
+    t!(test4: "Crème Brûlée", Snake => "\"crème_brûlée\"");
+    t!(test5: "ΣΑΣ key", Kebab => "\"σας-key\"");
+    t!(test6: "table name", Title => "\"Table Name\"");
+    t!(test7: "O'Brien", Snake => "\"o'brien\"");
+    t!(test8: "", Snake => "\"\"");
+}
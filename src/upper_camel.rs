@@ -1,4 +1,6 @@
-use crate::{capitalize, transform};
+use std::borrow::Cow;
+
+use crate::{capitalize, join, to_camel_case, Words};
 
 /// This trait defines an upper camel case conversion.
 ///
@@ -16,11 +18,78 @@ use crate::{capitalize, transform};
 pub trait ToUpperCamelCase: ToOwned {
     /// Convert this type to upper camel case.
     fn to_upper_camel_case(&self) -> Self::Owned;
+
+    /// Convert this type to upper camel case, keeping a file extension as it
+    /// is.
+    ///
+    /// The extension starts at the last full stop. A full stop at the start
+    /// of the string, as in ".gitignore", does not start an extension, and of
+    /// several extensions only the last one is kept. Full stops in the rest
+    /// of the name separate words, so "my_file.v2.json" becomes
+    /// "MyFileV2.json".
+    ///
+    /// ```rust
+    /// use heck::ToUpperCamelCase;
+    ///
+    /// assert_eq!("my_data.json".to_upper_camel_case_keep_ext(), "MyData.json");
+    /// ```
+    fn to_upper_camel_case_keep_ext(&self) -> Self::Owned;
+
+    /// Convert this type to upper camel case, first passing the last word
+    /// through `f`.
+    ///
+    /// `f` receives the last word as it appears in the input, and whatever it
+    /// returns is capitalized like any other word. This is a hook for
+    /// inflections such as singularizing a table name into a type name,
+    /// which heck does not provide itself.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    ///
+    /// use heck::ToUpperCamelCase;
+    ///
+    /// fn singular(word: &str) -> Cow<'_, str> {
+    ///     Cow::Borrowed(word.trim_end_matches('s'))
+    /// }
+    ///
+    /// assert_eq!("users".to_upper_camel_case_with_last_word(singular), "User");
+    /// assert_eq!("blog_posts".to_upper_camel_case_with_last_word(singular), "BlogPost");
+    /// ```
+    fn to_upper_camel_case_with_last_word<F>(&self, f: F) -> Self::Owned
+    where
+        F: Fn(&str) -> Cow<'_, str>;
 }
 
 impl ToUpperCamelCase for str {
     fn to_upper_camel_case(&self) -> String {
-        transform(self, capitalize, |_| {})
+        to_camel_case(self, true)
+    }
+
+    fn to_upper_camel_case_keep_ext(&self) -> String {
+        match self.rfind('.') {
+            Some(dot) if dot > 0 => {
+                let mut out: String = self[..dot]
+                    .split('.')
+                    .map(|part| part.to_upper_camel_case())
+                    .collect();
+                out.push_str(&self[dot..]);
+                out
+            }
+            _ => self.to_upper_camel_case(),
+        }
+    }
+
+    fn to_upper_camel_case_with_last_word<F>(&self, f: F) -> String
+    where
+        F: Fn(&str) -> Cow<'_, str>,
+    {
+        let mut words: Vec<(usize, &str)> = Words::new(self).collect();
+        let last = words.pop();
+        let mut out = join(self, words, capitalize, |_| {});
+        if let Some((_, last)) = last {
+            capitalize(&f(last), &mut out);
+        }
+        out
     }
 }
 
@@ -60,4 +129,84 @@ mod tests {
     t!(test8: "this-contains_ ALLKinds OfWord_Boundaries" => "ThisContainsAllKindsOfWordBoundaries");
     t!(test9: "XΣXΣ baﬄe" => "XσxςBaﬄe");
     t!(test10: "XMLHttpRequest" => "XmlHttpRequest");
+    t!(test11: "ǆungla_ǉubav" => "ǅunglaǈubav");
+    t!(test12: "foo\u{200b}bar" => "FooBar");
+    t!(test13: "foo\u{200c}bar" => "Foobar");
+    t!(test14: "A" => "A");
+    t!(test15: "a" => "A");
+    t!(test16: "5" => "5");
+    t!(test17: "_" => "");
+    t!(test18: "parseXMLData" => "ParseXmlData");
+    t!(test19: "theURLParser" => "TheUrlParser");
+    t!(test20: "\u{FEFF}fooBar" => "FooBar");
+    t!(test21: "soft\u{00AD}ware" => "Software");
+    t!(test22: "foo\r\nbar\tbaz" => "FooBarBaz");
+    t!(test23: "O'Brien" => "O'Brien");
+    t!(test24: "O\u{2019}Brien" => "O\u{2019}Brien");
+
+    mod keep_ext {
+        use super::super::ToUpperCamelCase;
+
+        macro_rules! t {
+            ($t:ident : $s1:expr => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    assert_eq!($s1.to_upper_camel_case_keep_ext(), $s2)
+                }
+            };
+        }
+
+        t!(test1: "my_data.json" => "MyData.json");
+        t!(test2: "my-data" => "MyData");
+        t!(test3: ".gitignore" => "Gitignore");
+        t!(test4: "a.tar.gz" => "ATar.gz");
+        t!(test5: "archive_name.tar.gz" => "ArchiveNameTar.gz");
+        t!(test6: "my_data.JSON" => "MyData.JSON");
+        t!(test7: "file." => "File.");
+        t!(test8: "my_file.v2.json" => "MyFileV2.json");
+        t!(test9: "my.file.json" => "MyFile.json");
+        t!(test10: "..json" => ".json");
+    }
+
+    mod with_last_word {
+        use std::borrow::Cow;
+
+        use super::super::ToUpperCamelCase;
+
+        fn singular(word: &str) -> Cow<'_, str> {
+            if word.ends_with('s') {
+                Cow::Borrowed(&word[..word.len() - 1])
+            } else {
+                Cow::Borrowed(word)
+            }
+        }
+
+        macro_rules! t {
+            ($t:ident : $s1:expr => $s2:expr) => {
+                #[test]
+                fn $t() {
+                    assert_eq!($s1.to_upper_camel_case_with_last_word(singular), $s2)
+                }
+            };
+        }
+
+        t!(test1: "users" => "User");
+        t!(test2: "blog_posts" => "BlogPost");
+        t!(test3: "userCount" => "UserCount");
+        t!(test4: "order-items" => "OrderItem");
+        t!(test5: "" => "");
+        t!(test6: "s" => "");
+
+        #[test]
+        fn owned_replacement() {
+            let plural = "team_person".to_upper_camel_case_with_last_word(|word| {
+                if word == "person" {
+                    Cow::Owned("people".to_owned())
+                } else {
+                    Cow::Borrowed(word)
+                }
+            });
+            assert_eq!(plural, "TeamPeople");
+        }
+    }
 }
@@ -0,0 +1,607 @@
+use std::borrow::Cow;
+use std::char::ToUppercase;
+use std::iter::{self, FromIterator};
+use std::ops::Range;
+use std::str::Chars;
+use std::vec;
+
+use crate::insensitive::snake_chars;
+use crate::{
+    fix_final_sigma, is_ignorable, lowercase_word, Case, Converter, LowercaseChars, Words,
+};
+
+/// Counts the words of a string, as they would be split by the case
+/// conversions.
+///
+/// This is cheaper than converting the string and counting the separators in
+/// the result.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::word_count;
+///
+/// assert_eq!(word_count("XMLHttpRequest"), 3);
+/// ```
+pub fn word_count(s: &str) -> usize {
+    Words::new(s).count()
+}
+
+/// The length in bytes of a string converted to snake case.
+///
+/// This is computed without building the converted string, which is useful
+/// to size a buffer in advance. Lowercasing can change the length of a
+/// character, such as "İ" which becomes "i̇", so this is not the length of
+/// the input without its separators.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{snake_case_len, ToSnakeCase};
+///
+/// assert_eq!(snake_case_len("XMLHttpRequest"), 16);
+/// assert_eq!(snake_case_len("İstanbul"), "İstanbul".to_snake_case().len());
+/// ```
+pub fn snake_case_len(s: &str) -> usize {
+    Words::new(s)
+        .enumerate()
+        .map(|(index, (offset, word))| {
+            let separator = if index == 0 { 0 } else { 1 };
+            let lower = LowercaseChars::in_context(s, offset, word);
+            separator + lower.map(char::len_utf8).sum::<usize>()
+        })
+        .sum()
+}
+
+/// Makes an acronym of the first character of each word of a string,
+/// lowercased.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::to_acronym;
+///
+/// assert_eq!(to_acronym("XMLHttpRequest"), "xhr");
+/// assert_eq!(to_acronym("foo_bar_baz"), "fbb");
+/// ```
+pub fn to_acronym(s: &str) -> String {
+    initials(s).flat_map(char::to_lowercase).collect()
+}
+
+/// Makes an acronym of the first character of each word of a string,
+/// uppercased.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::to_shouty_acronym;
+///
+/// assert_eq!(to_shouty_acronym("XMLHttpRequest"), "XHR");
+/// ```
+pub fn to_shouty_acronym(s: &str) -> String {
+    initials(s).flat_map(char::to_uppercase).collect()
+}
+
+/// The first character of each word of `s`.
+fn initials(s: &str) -> impl Iterator<Item = char> + '_ {
+    Words::new(s).filter_map(|(_, word)| word.chars().find(|&c| !is_ignorable(c)))
+}
+
+/// Converts a string to both snake_case and lowerCamelCase, splitting it into
+/// words only once.
+///
+/// This is useful for generating struct fields along with their names on the
+/// wire, and gives the same results as `ToSnakeCase` and `ToLowerCamelCase`.
+/// Use `Converter::convert_many` for other combinations of cases.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::snake_and_camel;
+///
+/// assert_eq!(
+///     snake_and_camel("HTTPStatusCode"),
+///     ("http_status_code".to_owned(), "httpStatusCode".to_owned())
+/// );
+/// ```
+pub fn snake_and_camel(s: &str) -> (String, String) {
+    let mut converted = Converter::new()
+        .convert_many(s, &[Case::Snake, Case::LowerCamel])
+        .into_iter();
+    let snake = converted.next().unwrap();
+    let camel = converted.next().unwrap();
+    (snake, camel)
+}
+
+/// Converts a string to snake case, as a series of chunks which are either a
+/// word or the separator between two words.
+///
+/// Concatenating the chunks gives the same result as `ToSnakeCase`. This is
+/// useful for template engines which style words and separators differently.
+/// Words which are already in snake case are borrowed from the string.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::snake_case_chunks;
+///
+/// let chunks: Vec<_> = snake_case_chunks("fooBar").collect();
+/// assert_eq!(chunks, ["foo", "_", "bar"]);
+/// ```
+pub fn snake_case_chunks(s: &str) -> impl Iterator<Item = Cow<'_, str>> {
+    Words::new(s)
+        .enumerate()
+        .flat_map(move |(i, (offset, word))| {
+            let separator = if i == 0 {
+                None
+            } else {
+                Some(Cow::Borrowed("_"))
+            };
+            separator
+                .into_iter()
+                .chain(iter::once(lowercase_cow(s, offset, word)))
+        })
+}
+
+/// Splits a string into its snake case words, replacing the contents of `out`.
+///
+/// The words are lowercased into the strings already in `out`, so that
+/// converting many strings with the same vector reuses its allocations.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::snake_case_into_vec;
+///
+/// let mut words = Vec::new();
+/// snake_case_into_vec("XMLHttpRequest", &mut words);
+/// assert_eq!(words, ["xml", "http", "request"]);
+/// snake_case_into_vec("fooBar", &mut words);
+/// assert_eq!(words, ["foo", "bar"]);
+/// ```
+pub fn snake_case_into_vec(s: &str, out: &mut Vec<String>) {
+    let mut len = 0;
+
+    for (offset, word) in Words::new(s) {
+        if len == out.len() {
+            out.push(String::new());
+        }
+        out[len].clear();
+        lowercase_word(s, offset, word, &mut out[len]);
+        len += 1;
+    }
+
+    out.truncate(len);
+}
+
+/// Converts a string of characters to snake case, replacing the contents of
+/// `dst`.
+///
+/// This is not free of allocations: words are split on UTF-8 text, so the
+/// characters of `src` are first collected into a temporary string. Only
+/// the allocation of `dst` is reused, and the converted characters are
+/// written to it directly.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::snake_case_chars_into;
+///
+/// let src: Vec<char> = "XMLHttpRequest".chars().collect();
+/// let mut dst = Vec::new();
+/// snake_case_chars_into(&src, &mut dst);
+/// assert_eq!(dst.iter().collect::<String>(), "xml_http_request");
+/// ```
+pub fn snake_case_chars_into(src: &[char], dst: &mut Vec<char>) {
+    let s: String = src.iter().collect();
+    dst.clear();
+    dst.extend(snake_chars(&s));
+}
+
+/// Lowercases `word`, which is found at `offset` in `s`, borrowing it if it
+/// is ASCII without uppercase letters.
+fn lowercase_cow<'a>(s: &str, offset: usize, word: &'a str) -> Cow<'a, str> {
+    if word
+        .bytes()
+        .all(|b| b.is_ascii() && !b.is_ascii_uppercase())
+    {
+        Cow::Borrowed(word)
+    } else {
+        let mut out = String::new();
+        lowercase_word(s, offset, word, &mut out);
+        Cow::Owned(out)
+    }
+}
+
+/// Joins words which have already been split, casing each of them for
+/// `case`.
+///
+/// The words are not split any further, so this is faster than joining them
+/// and converting the result, and respects the caller's tokenization. Empty
+/// words are skipped.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{join_words, Case};
+///
+/// assert_eq!(join_words(&["foo", "Bar", "BAZ"], Case::Snake), "foo_bar_baz");
+/// assert_eq!(join_words(&["XMLHttp", "request"], Case::UpperCamel), "XmlhttpRequest");
+/// ```
+pub fn join_words<I>(words: I, case: Case) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut out = String::new();
+
+    for (index, word) in words
+        .into_iter()
+        .filter(|word| !word.as_ref().is_empty())
+        .enumerate()
+    {
+        if index != 0 {
+            out.push_str(case.separator());
+        }
+        case.push_word(index, word.as_ref(), None, &mut out);
+    }
+
+    out
+}
+
+/// Joins the words of a string with `separator`, keeping the case of each
+/// word as it is.
+///
+/// The string is split into words like the case conversions do, but no
+/// letter is recased, so this only replaces the separators between words.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::to_separated;
+///
+/// assert_eq!(to_separated("Foo Bar  BAZ", "_"), "Foo_Bar_BAZ");
+/// assert_eq!(to_separated("fooBar", "."), "foo.Bar");
+/// ```
+pub fn to_separated(s: &str, separator: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for (index, (_, word)) in Words::new(s).enumerate() {
+        if index != 0 {
+            out.push_str(separator);
+        }
+        out.push_str(word);
+    }
+
+    out
+}
+
+/// Splits a string into words like the case conversions do, yielding the
+/// byte range of each word in the string along with the word itself.
+///
+/// The words are slices of the string, not recased, so the ranges can be
+/// used to map them back to source positions, for example to highlight each
+/// word of an identifier.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::word_spans;
+///
+/// let spans: Vec<_> = word_spans("XMLHttpRequest").collect();
+/// assert_eq!(spans, [(0..3, "XML"), (3..7, "Http"), (7..14, "Request")]);
+/// ```
+pub fn word_spans(s: &str) -> impl Iterator<Item = (Range<usize>, &str)> {
+    Words::new(s).map(|(offset, word)| (offset..offset + word.len(), word))
+}
+
+/// Converts a string to `case`, collecting the characters of the result into
+/// any `FromIterator<char>` type, such as `String` or `Vec<char>`.
+///
+/// The characters are produced one at a time, so no intermediate string is
+/// built for the lowercase and uppercase cases. The result is the same as
+/// converting with `Converter::new`.
+///
+/// ## Example:
+///
+/// ```rust
+/// use heck::{collect_case, Case};
+///
+/// let snake: String = collect_case("fooBar", Case::Snake);
+/// assert_eq!(snake, "foo_bar");
+///
+/// let kebab: Vec<char> = collect_case("fooBar", Case::Kebab);
+/// assert_eq!(kebab, ['f', 'o', 'o', '-', 'b', 'a', 'r']);
+/// ```
+pub fn collect_case<B>(s: &str, case: Case) -> B
+where
+    B: FromIterator<char>,
+{
+    let separator = case.separator().chars().next();
+    Words::new(s)
+        .enumerate()
+        .flat_map(move |(index, (offset, word))| {
+            let separator = if index == 0 { None } else { separator };
+            separator
+                .into_iter()
+                .chain(WordChars::new(s, offset, word, index, case))
+        })
+        .collect()
+}
+
+/// The characters of a word, which is found at `offset` in `s` and is the word
+/// at `index` in the output, cased for `case`.
+enum WordChars<'a> {
+    Lower(LowercaseChars<'a>),
+    Upper(Chars<'a>, Option<ToUppercase>),
+    /// The characters of a capitalized word, which depend on more than one
+    /// character at a time and so are cased in advance.
+    Cased(vec::IntoIter<char>),
+}
+
+impl<'a> WordChars<'a> {
+    fn new(s: &'a str, offset: usize, word: &'a str, index: usize, case: Case) -> WordChars<'a> {
+        match case {
+            Case::Snake | Case::Kebab => {
+                WordChars::Lower(LowercaseChars::in_context(s, offset, word))
+            }
+            Case::LowerCamel if index == 0 => {
+                WordChars::Lower(LowercaseChars::in_context(s, offset, word))
+            }
+            Case::ShoutySnake | Case::ShoutyKebab => WordChars::Upper(word.chars(), None),
+            Case::UpperCamel | Case::LowerCamel | Case::Title => {
+                let mut out = String::new();
+                case.push_word(index, word, None, &mut out);
+                fix_final_sigma(s, offset, word, &mut out, 0);
+                WordChars::Cased(out.chars().collect::<Vec<_>>().into_iter())
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for WordChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            WordChars::Lower(chars) => chars.next(),
+            WordChars::Upper(chars, pending) => {
+                if let Some(c) = pending.as_mut().and_then(Iterator::next) {
+                    return Some(c);
+                }
+                let mut upper = chars.find(|&c| !is_ignorable(c))?.to_uppercase();
+                let first = upper.next();
+                *pending = Some(upper);
+                first
+            }
+            WordChars::Cased(chars) => chars.next(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{
+        collect_case, join_words, snake_and_camel, snake_case_chars_into, snake_case_chunks,
+        snake_case_into_vec, snake_case_len, to_acronym, to_separated, to_shouty_acronym,
+        word_count, word_spans,
+    };
+    use crate::{Case, Converter, ToLowerCamelCase, ToSnakeCase};
+
+    macro_rules! t {
+        ($t:ident : $s1:expr => $n:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(word_count($s1), $n)
+            }
+        };
+    }
+
+    t!(test1: "XMLHttpRequest" => 3);
+    t!(test2: "foo_bar_baz" => 3);
+    t!(test3: "This is Human case." => 4);
+    t!(test4: "__foo__" => 1);
+    t!(test5: "" => 0);
+    t!(test6: "!?" => 0);
+
+    macro_rules! a {
+        ($t:ident : $s1:expr => $s2:expr, $s3:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(to_acronym($s1), $s2);
+                assert_eq!(to_shouty_acronym($s1), $s3);
+            }
+        };
+    }
+
+    a!(acronym1: "XMLHttpRequest" => "xhr", "XHR");
+    a!(acronym2: "foo_bar_baz" => "fbb", "FBB");
+    a!(acronym3: "portable network graphics" => "png", "PNG");
+    a!(acronym4: "über Änderung" => "üä", "ÜÄ");
+    a!(acronym5: "" => "", "");
+
+    macro_rules! j {
+        ($t:ident : $words:expr, $case:ident => $s2:expr) => {
+            #[test]
+            fn $t() {
+                assert_eq!(join_words(&$words, Case::$case), $s2)
+            }
+        };
+    }
+
+    j!(join1: ["foo", "Bar"], Snake => "foo_bar");
+    j!(join2: ["foo", "Bar", "BAZ"], LowerCamel => "fooBarBaz");
+    j!(join3: ["foo", "Bar", "BAZ"], ShoutyKebab => "FOO-BAR-BAZ");
+    j!(join4: ["fooBar", "baz"], Kebab => "foobar-baz");
+    j!(join5: ["", "foo", "", "bar"], Title => "Foo Bar");
+    j!(join6: ["ΣΑΣ"], Snake => "σας");
+
+    #[test]
+    fn join_owned() {
+        let words = vec![String::from("x"), String::from("coord")];
+        assert_eq!(join_words(words, Case::UpperCamel), "XCoord");
+    }
+
+    #[test]
+    fn snake_and_camel_matches_traits() {
+        for s in &[
+            "user_id",
+            "HTTPStatusCode",
+            "This is Human case.",
+            "XΣXΣ baﬄe",
+            "",
+        ] {
+            assert_eq!(
+                snake_and_camel(s),
+                (s.to_snake_case(), s.to_lower_camel_case())
+            );
+        }
+    }
+
+    #[test]
+    fn chunks_concatenate_to_snake_case() {
+        for s in &[
+            "fooBar",
+            "XMLHttpRequest",
+            "  __x__  ",
+            "XΣXΣ baﬄe",
+            "foo\u{200c}bar",
+            "",
+        ] {
+            assert_eq!(snake_case_chunks(s).collect::<String>(), s.to_snake_case());
+        }
+    }
+
+    #[test]
+    fn chunks_borrow_lowercase_words() {
+        let chunks: Vec<_> = snake_case_chunks("foo_Bar").collect();
+        assert_eq!(chunks, ["foo", "_", "bar"]);
+        assert!(match chunks[0] {
+            Cow::Borrowed(_) => true,
+            Cow::Owned(_) => false,
+        });
+        assert!(match chunks[2] {
+            Cow::Borrowed(_) => false,
+            Cow::Owned(_) => true,
+        });
+    }
+
+    #[test]
+    fn into_vec_reuses_strings() {
+        let mut words = Vec::new();
+        snake_case_into_vec("Some Longer Words", &mut words);
+        assert_eq!(words, ["some", "longer", "words"]);
+        let capacity = words[1].capacity();
+
+        snake_case_into_vec("aB", &mut words);
+        assert_eq!(words, ["a", "b"]);
+        assert_eq!(words[1].capacity(), capacity);
+
+        snake_case_into_vec("ΣΑΣ one two three", &mut words);
+        assert_eq!(words, ["σας", "one", "two", "three"]);
+
+        snake_case_into_vec("", &mut words);
+        assert!(words.is_empty());
+    }
+
+    #[test]
+    fn separated_keeps_case() {
+        assert_eq!(to_separated("Foo Bar", "_"), "Foo_Bar");
+        assert_eq!(to_separated("  Foo\t\tBar BAZ ", "_"), "Foo_Bar_BAZ");
+        assert_eq!(to_separated("foo_bar-Baz", " "), "foo bar Baz");
+        assert_eq!(to_separated("XMLHttpRequest", "-"), "XML-Http-Request");
+        assert_eq!(to_separated("ΣΑΣ", "_"), "ΣΑΣ");
+        assert_eq!(to_separated("Foo Bar", ""), "FooBar");
+        assert_eq!(to_separated("", "_"), "");
+    }
+
+    #[test]
+    fn len_matches_snake_case() {
+        for s in &[
+            "XMLHttpRequest",
+            "Straße STRASSE ẞ",
+            "baﬄe ﬁnancial",
+            "İstanbul",
+            "ȺȾ",
+            "XΣXΣ ΣΑΣ Σ",
+            "soft\u{00AD}ware\u{200b}Foo",
+            "  __ ",
+            "",
+        ] {
+            assert_eq!(snake_case_len(s), s.to_snake_case().len(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn collect_matches_convert() {
+        let converter = Converter::new();
+        for s in &[
+            "XMLHttpRequest",
+            "This is Human case.",
+            "XΣXΣ baﬄe ΛΌΓΟΣ",
+            "ǆemal O'Brien straße",
+            "foo\u{200c}bar\u{200b}baz",
+            "",
+        ] {
+            for &case in &[
+                Case::UpperCamel,
+                Case::LowerCamel,
+                Case::Snake,
+                Case::Kebab,
+                Case::ShoutySnake,
+                Case::ShoutyKebab,
+                Case::Title,
+            ] {
+                let converted = converter.convert(s, case);
+                assert_eq!(collect_case::<String>(s, case), converted);
+                assert_eq!(
+                    collect_case::<Vec<char>>(s, case),
+                    converted.chars().collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn spans() {
+        let spans: Vec<_> = word_spans("XMLHttpRequest").collect();
+        assert_eq!(spans, [(0..3, "XML"), (3..7, "Http"), (7..14, "Request")]);
+
+        let spans: Vec<_> = word_spans("  foo_bar-Baz").collect();
+        assert_eq!(spans, [(2..5, "foo"), (6..9, "bar"), (10..13, "Baz")]);
+
+        let s = "naïveÉtude 🎉 x";
+        for (range, word) in word_spans(s) {
+            assert_eq!(&s[range], word);
+        }
+        assert_eq!(word_spans("").count(), 0);
+    }
+
+    #[test]
+    fn chars_into_matches_snake_case() {
+        let mut dst = Vec::new();
+        for s in &[
+            "XMLHttpRequest",
+            "This is Human case.",
+            "XΣXΣ baﬄe",
+            "İstanbul",
+            "foo\u{200c}bar\u{200b}baz",
+            "",
+        ] {
+            let src: Vec<char> = s.chars().collect();
+            snake_case_chars_into(&src, &mut dst);
+            assert_eq!(dst.iter().collect::<String>(), s.to_snake_case());
+        }
+    }
+
+    #[test]
+    fn chars_into_reuses_allocation() {
+        let mut dst = Vec::with_capacity(64);
+        let before = dst.as_ptr();
+        snake_case_chars_into(&['f', 'o', 'o', 'B', 'a', 'r'], &mut dst);
+        assert_eq!(dst, ['f', 'o', 'o', '_', 'b', 'a', 'r']);
+        assert_eq!(dst.as_ptr(), before);
+    }
+}